@@ -20,6 +20,7 @@ pub fn compute_position<Element: Clone, Window: Clone>(
     let strategy = config.strategy.unwrap_or(Strategy::Absolute);
     let platform = config.platform;
     let middlewares = config.middleware.unwrap_or_default();
+    let previous_placement = config.previous_placement;
 
     let rtl = platform.is_rtl(floating);
 
@@ -47,6 +48,7 @@ pub fn compute_position<Element: Clone, Window: Clone>(
             y,
             initial_placement: placement,
             placement: stateful_placement,
+            previous_placement,
             strategy,
             middleware_data: &middleware_data,
             rects: &rects,
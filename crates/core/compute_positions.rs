@@ -0,0 +1,198 @@
+use floating_ui_utils::{ElementOrVirtual, Rect, Strategy};
+
+use crate::compute_position::compute_position;
+use crate::middleware::{Avoid, AvoidOptions, ARROW_NAME, HIDE_NAME, TRANSFORM_ORIGIN_NAME};
+use crate::types::{ComputePositionConfig, ComputePositionReturn, GetElementRectsArgs};
+
+/// Item of [`compute_positions`].
+pub struct ComputePositionsItem<'a, Element: Clone, Window: Clone> {
+    pub reference: ElementOrVirtual<'a, Element>,
+    pub floating: &'a Element,
+    pub config: ComputePositionConfig<'a, Element, Window>,
+
+    /// The minimum distance between the floating element and the floating elements of the previous items.
+    ///
+    /// Defaults to `0`.
+    pub padding: Option<f64>,
+}
+
+impl<'a, Element: Clone, Window: Clone> ComputePositionsItem<'a, Element, Window> {
+    pub fn new(
+        reference: ElementOrVirtual<'a, Element>,
+        floating: &'a Element,
+        config: ComputePositionConfig<'a, Element, Window>,
+    ) -> Self {
+        ComputePositionsItem {
+            reference,
+            floating,
+            config,
+            padding: None,
+        }
+    }
+
+    /// Set `padding` option.
+    pub fn padding(mut self, value: f64) -> Self {
+        self.padding = Some(value);
+        self
+    }
+}
+
+/// Computes the positions of multiple floating elements, so they do not overlap each other.
+///
+/// The items are positioned in order. Each floating element avoids the floating elements of the previous items
+/// with an [`Avoid`] middleware, which is inserted before [`Arrow`][`crate::middleware::Arrow`], [`Hide`][`crate::middleware::Hide`]
+/// and [`TransformOrigin`][`crate::middleware::TransformOrigin`] middleware.
+/// The [`AvoidData`][`crate::middleware::AvoidData`] of each return contains the indices of the previous items that could not be avoided.
+///
+/// The floating elements should share the same offset parent, so their coordinates are in the same coordinate space.
+pub fn compute_positions<Element: Clone + 'static, Window: Clone + 'static>(
+    items: Vec<ComputePositionsItem<Element, Window>>,
+) -> Vec<ComputePositionReturn> {
+    let mut obstacles: Vec<Rect> = Vec::with_capacity(items.len());
+    let mut positions = Vec::with_capacity(items.len());
+
+    for item in items {
+        let ComputePositionsItem {
+            reference,
+            floating,
+            mut config,
+            padding,
+        } = item;
+        let platform = config.platform;
+        let strategy = config.strategy.unwrap_or(Strategy::Absolute);
+
+        let mut avoid_options = AvoidOptions::new(obstacles.clone());
+        if let Some(padding) = padding {
+            avoid_options = avoid_options.padding(padding);
+        }
+
+        let mut middleware = config.middleware.unwrap_or_default();
+        let index = middleware
+            .iter()
+            .position(|middleware| {
+                [ARROW_NAME, HIDE_NAME, TRANSFORM_ORIGIN_NAME].contains(&middleware.name())
+            })
+            .unwrap_or(middleware.len());
+        middleware.insert(index, Box::new(Avoid::new(avoid_options)));
+        config.middleware = Some(middleware);
+
+        let position = compute_position(reference.clone(), floating, config);
+
+        let rects = platform.get_element_rects(GetElementRectsArgs {
+            reference,
+            floating,
+            strategy,
+        });
+        obstacles.push(Rect {
+            x: position.x,
+            y: position.y,
+            width: rects.floating.width,
+            height: rects.floating.height,
+        });
+
+        positions.push(position);
+    }
+
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        middleware::{
+            Arrow, ArrowData, ArrowOptions, AvoidData, Hide, HideData, HideOptions, HideStrategy,
+            TransformOrigin, TransformOriginData, AVOID_NAME,
+        },
+        test_utils::{config, Element, Window, FLOATING, REFERENCE},
+    };
+
+    use super::*;
+
+    fn item(
+        config: ComputePositionConfig<'static, Element, Window>,
+    ) -> ComputePositionsItem<'static, Element, Window> {
+        ComputePositionsItem::new((&REFERENCE).into(), &FLOATING, config)
+    }
+
+    #[test]
+    fn test_compute_positions() {
+        let positions = compute_positions(vec![item(config()), item(config())]);
+
+        assert_eq!(
+            positions
+                .iter()
+                .map(|position| (position.x, position.y))
+                .collect::<Vec<_>>(),
+            vec![(25.0, 100.0), (75.0, 100.0)]
+        );
+        assert_eq!(
+            positions[1]
+                .middleware_data
+                .get_as::<AvoidData>(AVOID_NAME)
+                .map(|data| data.avoided),
+            Some(vec![0])
+        );
+    }
+
+    #[test]
+    fn test_arrow_hide_and_transform_origin_after_avoid() {
+        let config = || {
+            config().middleware(vec![
+                Box::new(Arrow::new(ArrowOptions::new(Element::new(
+                    0.0, 0.0, 10.0, 10.0,
+                )))),
+                Box::new(Hide::new(
+                    HideOptions::default().strategy(HideStrategy::Escaped),
+                )),
+                Box::new(TransformOrigin::new()),
+            ])
+        };
+
+        let positions = compute_positions(vec![item(config()), item(config())]);
+        let data = positions
+            .iter()
+            .map(|position| {
+                (
+                    position.x,
+                    position
+                        .middleware_data
+                        .get_as::<ArrowData>(ARROW_NAME)
+                        .map(|data| data.center_offset),
+                    position
+                        .middleware_data
+                        .get_as::<HideData>(HIDE_NAME)
+                        .and_then(|data| data.escaped_offsets)
+                        .map(|offsets| offsets.left),
+                    position
+                        .middleware_data
+                        .get_as::<TransformOriginData>(TRANSFORM_ORIGIN_NAME)
+                        .map(|data| data.x),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The second floating element is moved next to the first one before the arrow, hide and transform origin data are computed.
+        assert_eq!(
+            data,
+            vec![
+                (25.0, Some(0.0), Some(-75.0), Some(25.0)),
+                (75.0, Some(-30.0), Some(-125.0), Some(5.0))
+            ]
+        );
+
+        // Without an arrow, the transform origin is clamped to the moved floating element.
+        let config = || config().middleware(vec![Box::new(TransformOrigin::new())]);
+        let positions = compute_positions(vec![item(config()), item(config())]);
+
+        assert_eq!(
+            positions
+                .iter()
+                .map(|position| position
+                    .middleware_data
+                    .get_as::<TransformOriginData>(TRANSFORM_ORIGIN_NAME)
+                    .map(|data| data.x))
+                .collect::<Vec<_>>(),
+            vec![Some(25.0), Some(0.0)]
+        );
+    }
+}
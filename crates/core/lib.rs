@@ -6,11 +6,15 @@
 
 pub use compute_coords_from_placement::*;
 pub use compute_position::*;
+pub use compute_positions::*;
 pub use detect_overflow::*;
 pub use types::*;
 
 mod compute_coords_from_placement;
 mod compute_position;
+mod compute_positions;
 mod detect_overflow;
 pub mod middleware;
+#[cfg(test)]
+mod test_utils;
 mod types;
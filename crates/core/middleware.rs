@@ -2,18 +2,28 @@
 
 pub use arrow::*;
 pub use auto_placement::*;
+pub use avoid::*;
 pub use flip::*;
 pub use hide::*;
 pub use inline::*;
+pub use inner::*;
 pub use offset::*;
+pub use placement_scorer::*;
 pub use shift::*;
 pub use size::*;
+pub use sticky_placement::*;
+pub use transform_origin::*;
 
 mod arrow;
 mod auto_placement;
+mod avoid;
 mod flip;
 mod hide;
 mod inline;
+mod inner;
 mod offset;
+mod placement_scorer;
 mod shift;
 mod size;
+mod sticky_placement;
+mod transform_origin;
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 use floating_ui_utils::{
     Axis, clamp, Coords, get_alignment, get_alignment_axis, get_axis_length, get_padding_object,
-    OwnedElementOrWindow, Padding, Side,
+    get_side, OwnedElementOrWindow, Padding, Side,
 };
 
 use crate::types::{
@@ -23,6 +23,12 @@ pub struct ArrowOptions<Element: Clone> {
     ///
     /// Defaults to `0` on all sides.
     pub padding: Option<Padding>,
+
+    /// The offset of the arrow tip from the start of the arrow element along the alignment axis.
+    /// Useful for asymmetric arrow shapes, where the tip is not centered.
+    ///
+    /// Defaults to half the length of the arrow element.
+    pub tip_offset: Option<f64>,
 }
 
 impl<Element: Clone> ArrowOptions<Element> {
@@ -30,6 +36,7 @@ impl<Element: Clone> ArrowOptions<Element> {
         ArrowOptions {
             element,
             padding: None,
+            tip_offset: None,
         }
     }
 
@@ -44,6 +51,12 @@ impl<Element: Clone> ArrowOptions<Element> {
         self.padding = Some(value);
         self
     }
+
+    /// Set `tip_offset` option.
+    pub fn tip_offset(mut self, value: f64) -> Self {
+        self.tip_offset = Some(value);
+        self
+    }
 }
 
 /// Data stored by [`Arrow`] middleware.
@@ -53,6 +66,15 @@ pub struct ArrowData {
     pub y: Option<f64>,
     pub center_offset: f64,
     pub alignment_offset: Option<f64>,
+
+    /// Whether the arrow tip no longer points at the reference element, so the arrow should be hidden.
+    pub is_detached: bool,
+
+    /// The side of the floating element the arrow is on.
+    pub side: Side,
+
+    /// The rotation in degrees of an arrow pointing up, so it points at the reference element.
+    pub rotation: f64,
 }
 
 /// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
@@ -119,6 +141,8 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Arrow<'a
         let axis = get_alignment_axis(placement);
         let length = get_axis_length(axis);
         let arrow_dimensions = platform.get_dimensions(&options.element);
+        let arrow_length = arrow_dimensions.length(length);
+        let tip_offset = options.tip_offset.unwrap_or(arrow_length / 2.0);
         let min_prop = match axis {
             Axis::X => Side::Left,
             Axis::Y => Side::Top,
@@ -148,16 +172,14 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Arrow<'a
         let center_to_reference = end_diff / 2.0 - start_diff / 2.0;
 
         // If the padding is large enough that it causes the arrow to no longer be centered, modify the padding so that it is centered.
-        let largest_possible_padding =
-            client_size / 2.0 - arrow_dimensions.length(length) / 2.0 - 1.0;
+        let largest_possible_padding = client_size / 2.0 - arrow_length / 2.0 - 1.0;
         let min_padding = padding_object.side(min_prop).min(largest_possible_padding);
         let max_padding = padding_object.side(max_prop).min(largest_possible_padding);
 
         // Make sure the arrow doesn't overflow the floating element if the center point is outside the floating element's bounds.
         let min = min_padding;
-        let max = client_size - arrow_dimensions.length(length) - max_padding;
-        let center =
-            client_size / 2.0 - arrow_dimensions.length(length) / 2.0 + center_to_reference;
+        let max = client_size - arrow_length - max_padding;
+        let center = client_size / 2.0 - tip_offset + center_to_reference;
         let offset = clamp(min, center, max);
 
         // If the reference is small enough that the arrow's padding causes it to to point to nothing for an aligned placement, adjust the offset of the floating element itself.
@@ -167,10 +189,9 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Arrow<'a
             && center != offset
             && rects.reference.length(length) / 2.0
                 - (match center < min {
-                    true => min_padding,
-                    false => max_padding,
+                    true => min_padding + tip_offset,
+                    false => max_padding + arrow_length - tip_offset,
                 })
-                - arrow_dimensions.length(length) / 2.0
                 < 0.0;
         let alignment_offset = match should_add_offset {
             true => match center < min {
@@ -180,6 +201,18 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Arrow<'a
             false => 0.0,
         };
 
+        let tip = coords.axis(axis) + alignment_offset + offset + tip_offset;
+        let is_detached = tip < rects.reference.axis(axis)
+            || tip > rects.reference.axis(axis) + rects.reference.length(length);
+
+        let side = get_side(placement).opposite();
+        let rotation = match side {
+            Side::Top => 0.0,
+            Side::Right => 90.0,
+            Side::Bottom => 180.0,
+            Side::Left => 270.0,
+        };
+
         MiddlewareReturn {
             x: match axis {
                 Axis::X => Some(coords.axis(axis) + alignment_offset),
@@ -204,6 +237,9 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Arrow<'a
                         true => Some(alignment_offset),
                         false => None,
                     },
+                    is_detached,
+                    side,
+                    rotation,
                 })
                 .expect("Data should be valid JSON."),
             ),
@@ -219,3 +255,42 @@ impl<'a, Element: Clone, Window: Clone>
         &self.options
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{compute, config, Element, FLOATING, REFERENCE};
+
+    use super::*;
+
+    fn compute_data(options: ArrowOptions<Element>) -> Option<ArrowData> {
+        compute(
+            &REFERENCE,
+            &FLOATING,
+            config().middleware(vec![Box::new(Arrow::new(options))]),
+        )
+        .middleware_data
+        .get_as(ARROW_NAME)
+    }
+
+    #[test]
+    fn test_arrow() {
+        let arrow = Element::new(0.0, 0.0, 10.0, 10.0);
+
+        assert_eq!(
+            compute_data(ArrowOptions::new(arrow.clone())),
+            Some(ArrowData {
+                x: Some(20.0),
+                y: None,
+                center_offset: 0.0,
+                alignment_offset: None,
+                is_detached: false,
+                side: Side::Top,
+                rotation: 0.0,
+            })
+        );
+        assert_eq!(
+            compute_data(ArrowOptions::new(arrow).tip_offset(2.0)).and_then(|data| data.x),
+            Some(23.0)
+        );
+    }
+}
@@ -7,6 +7,10 @@ use floating_ui_utils::{
 
 use crate::{
     detect_overflow::{detect_overflow, DetectOverflowOptions},
+    middleware::{
+        placement_scorer::{PlacementScoreState, PlacementScorer},
+        sticky_placement::{get_sticky_placement, StickyPlacementOptions},
+    },
     types::{
         Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions, Reset, ResetValue,
@@ -91,6 +95,16 @@ pub struct AutoPlacementOptions<Element: Clone> {
     ///
     /// Defaults to all possible placements.
     pub allowed_placements: Option<Vec<Placement>>,
+
+    /// Whether to prefer the previous placement, so the placement is stable while scrolling.
+    ///
+    /// Defaults to [`Option::None`] (disabled).
+    pub sticky_placement: Option<StickyPlacementOptions>,
+
+    /// Scorer to rank the placements. Placements that fit are still preferred over placements that overflow.
+    ///
+    /// Defaults to the overflow of the placement along the main axis, and the cross axis if [`cross_axis`][`Self::cross_axis`] is enabled.
+    pub scorer: Option<Box<dyn PlacementScorer>>,
 }
 
 impl<Element: Clone> AutoPlacementOptions<Element> {
@@ -123,6 +137,18 @@ impl<Element: Clone> AutoPlacementOptions<Element> {
         self.allowed_placements = Some(value);
         self
     }
+
+    /// Set `sticky_placement` option.
+    pub fn sticky_placement(mut self, value: StickyPlacementOptions) -> Self {
+        self.sticky_placement = Some(value);
+        self
+    }
+
+    /// Set `scorer` option.
+    pub fn scorer(mut self, value: Box<dyn PlacementScorer>) -> Self {
+        self.scorer = Some(value);
+        self
+    }
 }
 
 impl<Element: Clone> Default for AutoPlacementOptions<Element> {
@@ -133,6 +159,8 @@ impl<Element: Clone> Default for AutoPlacementOptions<Element> {
             alignment: Default::default(),
             auto_alignment: Default::default(),
             allowed_placements: Default::default(),
+            sticky_placement: Default::default(),
+            scorer: Default::default(),
         }
     }
 }
@@ -206,6 +234,7 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window>
             rects,
             middleware_data,
             placement,
+            previous_placement,
             platform,
             elements,
             ..
@@ -303,11 +332,18 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window>
 
                     (
                         overflow.placement,
-                        match alignment.is_some() && cross_axis {
+                        match (&options.scorer, alignment.is_some() && cross_axis) {
+                            // The first overflow is always on the side of the placement.
+                            (Some(scorer), _) => scorer.score(PlacementScoreState::new(
+                                overflow.placement,
+                                &overflow.overflows,
+                                overflow.overflows[0],
+                                rects,
+                            )),
                             // Check along the main axis and main cross axis side.
-                            true => overflow.overflows[0..2].iter().sum(),
+                            (None, true) => overflow.overflows[0..2].iter().sum(),
                             // Check only the main axis.
-                            false => overflow.overflows[0],
+                            (None, false) => overflow.overflows[0],
                         },
                         overflow.overflows,
                     )
@@ -330,11 +366,41 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window>
                 })
                 .collect();
 
-            let reset_placement = placements_that_fit_on_each_side
+            let mut reset_placement = placements_that_fit_on_each_side
                 .first()
                 .map(|v| v.0)
                 .unwrap_or(placements_sorted_by_most_space[0].0);
 
+            // Keep the previous placement if it still fits or no other placement is better by the margin.
+            let sticky_options = options.sticky_placement.as_ref();
+            if let (Some(sticky_options), Some(sticky_placement)) = (
+                sticky_options,
+                get_sticky_placement(sticky_options, previous_placement, &placements),
+            ) {
+                let find = |placement: Placement| {
+                    placements_sorted_by_most_space
+                        .iter()
+                        .find(|overflow| overflow.0 == placement)
+                };
+
+                if let (Some(sticky_overflow), Some(reset_overflow)) =
+                    (find(sticky_placement), find(reset_placement))
+                {
+                    let sticky_fits = sticky_options.fits(
+                        &sticky_overflow.2[0..match get_alignment(sticky_overflow.0) {
+                            Some(_) => 2,
+                            None => 3,
+                        }],
+                    );
+
+                    if sticky_fits
+                        || sticky_options.within_margin(sticky_overflow.1, reset_overflow.1)
+                    {
+                        reset_placement = sticky_placement;
+                    }
+                }
+            }
+
             if reset_placement != placement {
                 return MiddlewareReturn {
                     x: None,
@@ -374,8 +440,25 @@ impl<'a, Element: Clone, Window: Clone>
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        test_utils::{compute, config, Element, Window, FLOATING, REFERENCE},
+        ComputePositionConfig,
+    };
+
     use super::*;
 
+    fn compute_placement(
+        config: ComputePositionConfig<Element, Window>,
+        options: AutoPlacementOptions<Element>,
+    ) -> Placement {
+        compute(
+            &REFERENCE,
+            &FLOATING,
+            config.middleware(vec![Box::new(AutoPlacement::new(options))]),
+        )
+        .placement
+    }
+
     #[test]
     fn test_base_placement() {
         assert_eq!(
@@ -487,4 +570,60 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_sticky_placement() {
+        let config = || config().previous_placement(Placement::Left);
+
+        // The previous placement overflows the clipping rect by 50, the best placement has 850 space left.
+        let best_placement = compute_placement(config(), AutoPlacementOptions::default());
+        assert_ne!(best_placement, Placement::Left);
+
+        assert_eq!(
+            compute_placement(
+                config(),
+                AutoPlacementOptions::default()
+                    .sticky_placement(StickyPlacementOptions::default().threshold(60.0))
+            ),
+            Placement::Left
+        );
+        assert_eq!(
+            compute_placement(
+                config(),
+                AutoPlacementOptions::default()
+                    .sticky_placement(StickyPlacementOptions::default().margin(1000.0))
+            ),
+            Placement::Left
+        );
+        assert_eq!(
+            compute_placement(
+                config(),
+                AutoPlacementOptions::default()
+                    .sticky_placement(StickyPlacementOptions::default().margin(100.0))
+            ),
+            best_placement
+        );
+    }
+
+    #[test]
+    fn test_scorer() {
+        let compute_preferred = |preferred_placement: Placement| {
+            let scorer =
+                move |state: PlacementScoreState| match state.placement == preferred_placement {
+                    true => 0.0,
+                    false => state.available_space,
+                };
+
+            compute_placement(
+                config(),
+                AutoPlacementOptions::default().scorer(Box::new(scorer)),
+            )
+        };
+
+        assert_eq!(compute_preferred(Placement::Right), Placement::Right);
+        assert_eq!(compute_preferred(Placement::Bottom), Placement::Bottom);
+
+        // Placements that fit are preferred over placements that overflow.
+        assert_ne!(compute_preferred(Placement::Top), Placement::Top);
+    }
 }
@@ -0,0 +1,468 @@
+use serde::{Deserialize, Serialize};
+
+use floating_ui_utils::{
+    get_alignment_axis, get_axis_length, get_opposite_placement, get_side, Axis, Placement, Rect,
+    Side,
+};
+
+use crate::{
+    detect_overflow::{detect_overflow, DetectOverflowOptions},
+    types::{
+        Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
+        MiddlewareWithOptions, Reset, ResetValue,
+    },
+};
+
+fn get_overlap(a: &Rect, b: &Rect, padding: f64) -> f64 {
+    let width = (a.x + a.width).min(b.x + b.width + padding) - a.x.max(b.x - padding);
+    let height = (a.y + a.height).min(b.y + b.height + padding) - a.y.max(b.y - padding);
+
+    width.max(0.0) * height.max(0.0)
+}
+
+fn get_intersecting(obstacles: &[Rect], rect: &Rect, padding: f64) -> Vec<usize> {
+    obstacles
+        .iter()
+        .enumerate()
+        .filter(|(_, obstacle)| get_overlap(rect, obstacle, padding) > 0.0)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Name of the [`Avoid`] middleware.
+pub const AVOID_NAME: &str = "avoid";
+
+/// Options for [`Avoid`] middleware.
+#[derive(Clone, Debug)]
+pub struct AvoidOptions<Element: Clone> {
+    /// Options for [`detect_overflow`].
+    ///
+    /// Defaults to [`DetectOverflowOptions::default`].
+    pub detect_overflow: Option<DetectOverflowOptions<Element>>,
+
+    /// The rects the floating element should not intersect, e.g. other floating elements.
+    /// Uses the same coordinate space as the `x` and `y` coordinates of the floating element.
+    pub obstacles: Vec<Rect>,
+
+    /// The minimum distance between the floating element and the obstacles.
+    ///
+    /// Defaults to `0`.
+    pub padding: Option<f64>,
+
+    /// Placements to try if the floating element can not be shifted away from the obstacles in the initial placement.
+    ///
+    /// Defaults to the opposite placement.
+    pub fallback_placements: Option<Vec<Placement>>,
+}
+
+impl<Element: Clone> AvoidOptions<Element> {
+    pub fn new(obstacles: Vec<Rect>) -> Self {
+        AvoidOptions {
+            detect_overflow: None,
+            obstacles,
+            padding: None,
+            fallback_placements: None,
+        }
+    }
+
+    /// Set `detect_overflow` option.
+    pub fn detect_overflow(mut self, value: DetectOverflowOptions<Element>) -> Self {
+        self.detect_overflow = Some(value);
+        self
+    }
+
+    /// Set `obstacles` option.
+    pub fn obstacles(mut self, value: Vec<Rect>) -> Self {
+        self.obstacles = value;
+        self
+    }
+
+    /// Set `padding` option.
+    pub fn padding(mut self, value: f64) -> Self {
+        self.padding = Some(value);
+        self
+    }
+
+    /// Set `fallback_placements` option.
+    pub fn fallback_placements(mut self, value: Vec<Placement>) -> Self {
+        self.fallback_placements = Some(value);
+        self
+    }
+}
+
+/// An overlap stored in [`AvoidData`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AvoidDataOverlap {
+    pub placement: Placement,
+    pub overlap: f64,
+}
+
+/// Data stored by [`Avoid`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AvoidData {
+    pub index: usize,
+    pub overlaps: Vec<AvoidDataOverlap>,
+
+    /// Indices of the obstacles that intersected the floating element at its preferred position and were avoided.
+    pub avoided: Vec<usize>,
+
+    /// Indices of the obstacles that still intersect the floating element, if they could not be avoided.
+    pub intersecting: Vec<usize>,
+}
+
+/// Avoids obstacles, such as other floating elements, by shifting the floating element along its alignment axis
+/// or by changing its placement, while respecting the clipping boundary.
+/// The floating element is only shifted as long as it still overlaps the reference element, like [`LimitShift`][`crate::middleware::LimitShift`].
+pub struct Avoid<'a, Element: Clone, Window: Clone> {
+    options: Derivable<'a, Element, Window, AvoidOptions<Element>>,
+}
+
+impl<'a, Element: Clone, Window: Clone> Avoid<'a, Element, Window> {
+    /// Constructs a new instance of this middleware.
+    pub fn new(options: AvoidOptions<Element>) -> Self {
+        Avoid {
+            options: options.into(),
+        }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options.
+    pub fn new_derivable(options: Derivable<'a, Element, Window, AvoidOptions<Element>>) -> Self {
+        Avoid { options }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options function.
+    pub fn new_derivable_fn(
+        options: DerivableFn<'a, Element, Window, AvoidOptions<Element>>,
+    ) -> Self {
+        Avoid {
+            options: options.into(),
+        }
+    }
+}
+
+impl<'a, Element: Clone, Window: Clone> Clone for Avoid<'a, Element, Window> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Avoid<'a, Element, Window> {
+    fn name(&self) -> &'static str {
+        AVOID_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let options = self.options.evaluate(state.clone());
+
+        let MiddlewareState {
+            x,
+            y,
+            placement,
+            initial_placement,
+            middleware_data,
+            elements,
+            rects,
+            ..
+        } = state;
+
+        let padding = options.padding.unwrap_or(0.0);
+        let obstacles = &options.obstacles;
+
+        let floating = Rect {
+            x,
+            y,
+            width: rects.floating.width,
+            height: rects.floating.height,
+        };
+        let intersecting = get_intersecting(obstacles, &floating, padding);
+
+        let data: AvoidData = middleware_data.get_as(self.name()).unwrap_or(AvoidData {
+            index: 0,
+            overlaps: vec![],
+            avoided: intersecting.clone(),
+            intersecting: vec![],
+        });
+
+        if intersecting.is_empty() {
+            return MiddlewareReturn {
+                x: None,
+                y: None,
+                data: Some(
+                    serde_json::to_value(AvoidData {
+                        intersecting: vec![],
+                        ..data
+                    })
+                    .expect("Data should be valid JSON."),
+                ),
+                reset: None,
+            };
+        }
+
+        let overflow = detect_overflow(
+            MiddlewareState {
+                elements: elements.clone(),
+                ..state
+            },
+            options.detect_overflow.unwrap_or_default(),
+        );
+
+        // Shift along the alignment axis to the closest position next to an obstacle, within the clipping boundary
+        // and while still overlapping the reference element.
+        let axis = get_alignment_axis(placement);
+        let length = get_axis_length(axis);
+        let (min_side, max_side) = match axis {
+            Axis::X => (Side::Left, Side::Right),
+            Axis::Y => (Side::Top, Side::Bottom),
+        };
+        let coord = floating.axis(axis);
+        let size = floating.length(length);
+        let min = coord + overflow.side(min_side);
+        let max = coord - overflow.side(max_side);
+        let reference_min = rects.reference.axis(axis) - size;
+        let reference_max = rects.reference.axis(axis) + rects.reference.length(length);
+
+        let mut candidates: Vec<f64> = obstacles
+            .iter()
+            .flat_map(|obstacle| {
+                [
+                    obstacle.axis(axis) - size - padding,
+                    obstacle.axis(axis) + obstacle.length(length) + padding,
+                ]
+            })
+            .filter(|candidate| {
+                *candidate >= min
+                    && *candidate <= max
+                    && *candidate > reference_min
+                    && *candidate < reference_max
+            })
+            .collect();
+        candidates.sort_by(|a, b| (a - coord).abs().total_cmp(&(b - coord).abs()));
+
+        let candidate = match overflow.side(get_side(placement)) <= 0.0 {
+            true => candidates
+                .into_iter()
+                .map(|candidate| match axis {
+                    Axis::X => Rect {
+                        x: candidate,
+                        ..floating.clone()
+                    },
+                    Axis::Y => Rect {
+                        y: candidate,
+                        ..floating.clone()
+                    },
+                })
+                .find(|rect| get_intersecting(obstacles, rect, padding).is_empty()),
+            false => None,
+        };
+
+        if let Some(candidate) = candidate {
+            return MiddlewareReturn {
+                x: Some(candidate.x),
+                y: Some(candidate.y),
+                data: Some(
+                    serde_json::to_value(AvoidData {
+                        intersecting: vec![],
+                        ..data
+                    })
+                    .expect("Data should be valid JSON."),
+                ),
+                reset: None,
+            };
+        }
+
+        let mut placements = options
+            .fallback_placements
+            .unwrap_or(vec![get_opposite_placement(initial_placement)]);
+        placements.retain(|placement| *placement != initial_placement);
+        placements.insert(0, initial_placement);
+
+        let mut overlaps = data.overlaps.clone();
+        overlaps.push(AvoidDataOverlap {
+            placement,
+            overlap: obstacles
+                .iter()
+                .map(|obstacle| get_overlap(&floating, obstacle, padding))
+                .sum(),
+        });
+
+        let next_index = data.index + 1;
+
+        // Try the next placement, otherwise use the placement with the least overlap.
+        let reset_placement = placements.get(next_index).copied().or_else(|| {
+            overlaps
+                .iter()
+                .min_by(|a, b| a.overlap.total_cmp(&b.overlap))
+                .map(|overlap| overlap.placement)
+                .filter(|reset_placement| *reset_placement != placement)
+        });
+
+        match reset_placement {
+            Some(reset_placement) => MiddlewareReturn {
+                x: None,
+                y: None,
+                data: Some(
+                    serde_json::to_value(AvoidData {
+                        index: next_index,
+                        overlaps,
+                        avoided: data.avoided,
+                        intersecting: vec![],
+                    })
+                    .expect("Data should be valid JSON."),
+                ),
+                reset: Some(Reset::Value(ResetValue {
+                    placement: Some(reset_placement),
+                    rects: None,
+                })),
+            },
+            None => MiddlewareReturn {
+                x: None,
+                y: None,
+                data: Some(
+                    serde_json::to_value(AvoidData {
+                        index: data.index,
+                        overlaps,
+                        avoided: data
+                            .avoided
+                            .into_iter()
+                            .filter(|index| !intersecting.contains(index))
+                            .collect(),
+                        intersecting,
+                    })
+                    .expect("Data should be valid JSON."),
+                ),
+                reset: None,
+            },
+        }
+    }
+}
+
+impl<'a, Element: Clone, Window: Clone>
+    MiddlewareWithOptions<Element, Window, AvoidOptions<Element>> for Avoid<'a, Element, Window>
+{
+    fn options(&self) -> &Derivable<Element, Window, AvoidOptions<Element>> {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        middleware::{
+            Arrow, ArrowData, ArrowOptions, Hide, HideData, HideOptions, HideStrategy, ARROW_NAME,
+            HIDE_NAME,
+        },
+        test_utils::{compute, config, Element, FLOATING, REFERENCE},
+    };
+
+    use super::*;
+
+    /// Obstacle below the reference element, where the floating element is placed by default.
+    const OBSTACLE: Rect = Rect {
+        x: 0.0,
+        y: 100.0,
+        width: 100.0,
+        height: 50.0,
+    };
+
+    /// Obstacle below the left part of the reference element, which leaves room to shift the floating element.
+    const NARROW_OBSTACLE: Rect = Rect {
+        x: 0.0,
+        y: 100.0,
+        width: 60.0,
+        height: 50.0,
+    };
+
+    #[test]
+    fn test_avoid() {
+        let position = compute(
+            &REFERENCE,
+            &FLOATING,
+            config().middleware(vec![Box::new(Avoid::new(AvoidOptions::new(vec![
+                NARROW_OBSTACLE,
+            ])))]),
+        );
+
+        assert_eq!(
+            (position.x, position.y, position.placement),
+            (60.0, 100.0, Placement::Bottom)
+        );
+        assert_eq!(
+            position.middleware_data.get_as::<AvoidData>(AVOID_NAME),
+            Some(AvoidData {
+                index: 0,
+                overlaps: vec![],
+                avoided: vec![0],
+                intersecting: vec![],
+            })
+        );
+
+        // Shifting past the obstacle would detach the floating element from the reference element, so the opposite placement is used.
+        let position = compute(
+            &REFERENCE,
+            &FLOATING,
+            config().middleware(vec![Box::new(Avoid::new(AvoidOptions::new(vec![
+                OBSTACLE,
+            ])))]),
+        );
+
+        assert_eq!(
+            (position.x, position.y, position.placement),
+            (25.0, -50.0, Placement::Top)
+        );
+        assert_eq!(
+            position.middleware_data.get_as::<AvoidData>(AVOID_NAME),
+            Some(AvoidData {
+                index: 1,
+                overlaps: vec![AvoidDataOverlap {
+                    placement: Placement::Bottom,
+                    overlap: 2500.0,
+                }],
+                avoided: vec![0],
+                intersecting: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn test_arrow_and_hide_after_avoid() {
+        let position = compute(
+            &REFERENCE,
+            &FLOATING,
+            config().middleware(vec![
+                Box::new(Avoid::new(AvoidOptions::new(vec![NARROW_OBSTACLE]))),
+                Box::new(Arrow::new(ArrowOptions::new(Element::new(
+                    0.0, 0.0, 10.0, 10.0,
+                )))),
+                Box::new(Hide::new(
+                    HideOptions::default().strategy(HideStrategy::Escaped),
+                )),
+            ]),
+        );
+        assert_eq!((position.x, position.y), (60.0, 100.0));
+
+        // The floating element was moved away from the center of the reference element, so the arrow is offset from it.
+        let arrow_data: ArrowData = position
+            .middleware_data
+            .get_as(ARROW_NAME)
+            .expect("Arrow data should be set.");
+        assert_eq!(
+            (
+                arrow_data.x,
+                arrow_data.center_offset,
+                arrow_data.is_detached
+            ),
+            (Some(0.0), -15.0, false)
+        );
+
+        let hide_data: HideData = position
+            .middleware_data
+            .get_as(HIDE_NAME)
+            .expect("Hide data should be set.");
+        assert_eq!(
+            hide_data.escaped_offsets.map(|offsets| offsets.left),
+            Some(-110.0)
+        );
+    }
+}
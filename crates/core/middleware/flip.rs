@@ -7,13 +7,24 @@ use floating_ui_utils::{
 
 use crate::{
     detect_overflow::{detect_overflow, DetectOverflowOptions},
-    middleware::arrow::{ARROW_NAME, ArrowData},
+    middleware::{
+        arrow::{ARROW_NAME, ArrowData},
+        placement_scorer::{PlacementScoreState, PlacementScorer},
+        sticky_placement::{get_sticky_placement, StickyPlacementOptions},
+    },
     types::{
         Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState,
         MiddlewareWithOptions, Reset, ResetValue,
     },
 };
 
+fn get_total_overflow(overflows: &[f64]) -> f64 {
+    overflows
+        .iter()
+        .filter(|overflow| **overflow > 0.0)
+        .sum::<f64>()
+}
+
 /// Name of the [`Flip`] middleware.
 pub const FLIP_NAME: &str = "flip";
 
@@ -62,6 +73,16 @@ pub struct FlipOptions<Element: Clone> {
     ///
     /// Defaults to `true`.
     pub flip_alignment: Option<bool>,
+
+    /// Whether to prefer the previous placement, so the placement is stable while scrolling.
+    ///
+    /// Defaults to [`Option::None`] (disabled).
+    pub sticky_placement: Option<StickyPlacementOptions>,
+
+    /// Scorer to rank the placements with [`FallbackStrategy::BestFit`].
+    ///
+    /// Defaults to the total overflow of the placement.
+    pub scorer: Option<Box<dyn PlacementScorer>>,
 }
 
 impl<Element: Clone> FlipOptions<Element> {
@@ -106,6 +127,18 @@ impl<Element: Clone> FlipOptions<Element> {
         self.flip_alignment = Some(value);
         self
     }
+
+    /// Set `sticky_placement` option.
+    pub fn sticky_placement(mut self, value: StickyPlacementOptions) -> Self {
+        self.sticky_placement = Some(value);
+        self
+    }
+
+    /// Set `scorer` option.
+    pub fn scorer(mut self, value: Box<dyn PlacementScorer>) -> Self {
+        self.scorer = Some(value);
+        self
+    }
 }
 
 impl<Element: Clone> Default for FlipOptions<Element> {
@@ -118,6 +151,8 @@ impl<Element: Clone> Default for FlipOptions<Element> {
             fallback_strategy: Default::default(),
             fallback_axis_side_direction: Default::default(),
             flip_alignment: Default::default(),
+            sticky_placement: Default::default(),
+            scorer: Default::default(),
         }
     }
 }
@@ -127,6 +162,9 @@ impl<Element: Clone> Default for FlipOptions<Element> {
 pub struct FlipDataOverflow {
     pub placement: Placement,
     pub overflows: Vec<f64>,
+
+    /// The overflow of the side of the placement, also if the main axis is not checked.
+    pub side_overflow: f64,
 }
 
 /// Data stored by [`Flip`] middleware.
@@ -186,6 +224,7 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
         let MiddlewareState {
             placement,
             initial_placement,
+            previous_placement,
             middleware_data,
             elements,
             rects,
@@ -242,6 +281,34 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
 
         placements.insert(0, initial_placement);
 
+        let sticky_options = options.sticky_placement.as_ref();
+        let sticky_placement =
+            get_sticky_placement(sticky_options, previous_placement, &placements);
+
+        if let Some(sticky_placement) = sticky_placement {
+            // Check the previous placement first.
+            placements.retain(|placement| *placement != sticky_placement);
+            placements.insert(0, sticky_placement);
+
+            if middleware_data.get(self.name()).is_none() && placement != sticky_placement {
+                return MiddlewareReturn {
+                    x: None,
+                    y: None,
+                    data: Some(
+                        serde_json::to_value(FlipData {
+                            index: 0,
+                            overflows: vec![],
+                        })
+                        .expect("Data should be valid JSON."),
+                    ),
+                    reset: Some(Reset::Value(ResetValue {
+                        placement: Some(sticky_placement),
+                        rects: None,
+                    })),
+                };
+            }
+        }
+
         let overflow = detect_overflow(
             MiddlewareState {
                 elements: elements.clone(),
@@ -265,10 +332,18 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
         overflows_data.push(FlipDataOverflow {
             placement,
             overflows: overflows.clone(),
+            side_overflow: overflow.side(side),
         });
 
+        let fits = match sticky_options {
+            Some(sticky_options) if sticky_placement == Some(placement) => {
+                sticky_options.fits(&overflows)
+            }
+            _ => overflows.iter().all(|side| *side <= 0.0),
+        };
+
         // One or more sides is overflowing.
-        if !overflows.into_iter().all(|side| side <= 0.0) {
+        if !fits {
             let next_index = data.index + 1;
             let next_placement = placements.get(next_index);
 
@@ -300,12 +375,22 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
 
             let mut reset_placement = reset_placement.first().map(|overflow| overflow.placement);
 
+            let get_score = |overflow: &FlipDataOverflow| match &options.scorer {
+                Some(scorer) => scorer.score(PlacementScoreState::new(
+                    overflow.placement,
+                    &overflow.overflows,
+                    overflow.side_overflow,
+                    rects,
+                )),
+                None => get_total_overflow(&overflow.overflows),
+            };
+
             // Otherwise fallback.
             if reset_placement.is_none() {
                 match fallback_strategy {
                     FallbackStrategy::BestFit => {
                         let mut placement: Vec<(Placement, f64)> = overflows_data
-                            .into_iter()
+                            .iter()
                             .filter(|overflow| {
                                 if has_fallback_axis_side_direction {
                                     let current_side_axis = get_side_axis(overflow.placement);
@@ -317,16 +402,7 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
                                     true
                                 }
                             })
-                            .map(|overflow| {
-                                (
-                                    overflow.placement,
-                                    overflow
-                                        .overflows
-                                        .into_iter()
-                                        .filter(|overflow| *overflow > 0.0)
-                                        .sum::<f64>(),
-                                )
-                            })
+                            .map(|overflow| (overflow.placement, get_score(overflow)))
                             .collect();
                         placement.sort_by(|a, b| a.1.total_cmp(&b.1));
 
@@ -341,6 +417,26 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Flip<'a,
                 }
             }
 
+            // Keep the previous placement if no other placement is better by the margin.
+            if let (Some(sticky_options), Some(sticky_placement)) =
+                (sticky_options, sticky_placement)
+            {
+                let score = |placement: Option<Placement>| {
+                    overflows_data
+                        .iter()
+                        .find(|overflow| Some(overflow.placement) == placement)
+                        .map(get_score)
+                };
+
+                if let (Some(sticky_score), Some(reset_score)) =
+                    (score(Some(sticky_placement)), score(reset_placement))
+                {
+                    if sticky_options.within_margin(sticky_score, reset_score) {
+                        reset_placement = Some(sticky_placement);
+                    }
+                }
+            }
+
             if placement != reset_placement.expect("Reset placement is not none.") {
                 return MiddlewareReturn {
                     x: None,
@@ -370,3 +466,110 @@ impl<'a, Element: Clone, Window: Clone> MiddlewareWithOptions<Element, Window, F
         &self.options
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::Rect;
+
+    use crate::{
+        test_utils::{compute, config, Element, TestPlatform, Window, FLOATING, REFERENCE},
+        ComputePositionConfig,
+    };
+
+    use super::*;
+
+    fn compute_placement(
+        config: ComputePositionConfig<Element, Window>,
+        options: FlipOptions<Element>,
+    ) -> Placement {
+        compute(
+            &REFERENCE,
+            &FLOATING,
+            config
+                .placement(Placement::Bottom)
+                .middleware(vec![Box::new(Flip::new(options))]),
+        )
+        .placement
+    }
+
+    #[test]
+    fn test_sticky_placement() {
+        let config = || config().previous_placement(Placement::Top);
+
+        // The previous placement overflows the clipping rect by 50.
+        assert_eq!(
+            compute_placement(config(), FlipOptions::default()),
+            Placement::Bottom
+        );
+        assert_eq!(
+            compute_placement(
+                config(),
+                FlipOptions::default().sticky_placement(StickyPlacementOptions::default())
+            ),
+            Placement::Bottom
+        );
+        assert_eq!(
+            compute_placement(
+                config(),
+                FlipOptions::default()
+                    .sticky_placement(StickyPlacementOptions::default().threshold(60.0))
+            ),
+            Placement::Top
+        );
+    }
+
+    #[test]
+    fn test_best_fit_scorer() {
+        // Both placements overflow the clipping rect, the bottom by 30 and the top by 40.
+        let platform = TestPlatform {
+            clipping_rect: Rect {
+                x: 0.0,
+                y: -10.0,
+                width: 1000.0,
+                height: 130.0,
+            },
+        };
+        let options = || FlipOptions::default().fallback_strategy(FallbackStrategy::BestFit);
+        let scorer = |state: PlacementScoreState| match state.placement {
+            Placement::Top => 0.0,
+            _ => 1.0,
+        };
+
+        assert_eq!(
+            compute_placement(ComputePositionConfig::new(&platform), options()),
+            Placement::Bottom
+        );
+        assert_eq!(
+            compute_placement(
+                ComputePositionConfig::new(&platform),
+                options().scorer(Box::new(scorer))
+            ),
+            Placement::Top
+        );
+    }
+
+    #[test]
+    fn test_best_fit_scorer_without_main_axis() {
+        // Both placements overflow the right side by 15, the bottom overflows by 40 and the top by 20.
+        let platform = TestPlatform {
+            clipping_rect: Rect {
+                x: 0.0,
+                y: -30.0,
+                width: 60.0,
+                height: 140.0,
+            },
+        };
+        let scorer = |state: PlacementScoreState| -state.available_space;
+
+        assert_eq!(
+            compute_placement(
+                ComputePositionConfig::new(&platform),
+                FlipOptions::default()
+                    .main_axis(false)
+                    .fallback_strategy(FallbackStrategy::BestFit)
+                    .scorer(Box::new(scorer))
+            ),
+            Placement::Top
+        );
+    }
+}
@@ -23,6 +23,31 @@ fn is_any_side_fully_clipped(overflow: &SideLength) -> bool {
     ALL_SIDES.into_iter().any(|side| overflow.side(side) >= 0.0)
 }
 
+fn get_visible_length(length: f64, start_overflow: f64, end_overflow: f64) -> f64 {
+    if length > 0.0 {
+        (length - start_overflow.max(0.0) - end_overflow.max(0.0)).clamp(0.0, length) / length
+    } else if start_overflow <= 0.0 && end_overflow <= 0.0 {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn get_visible_ratio(overflow: &SideLength, rect: &Rect) -> f64 {
+    get_visible_length(rect.width, overflow.left, overflow.right)
+        * get_visible_length(rect.height, overflow.top, overflow.bottom)
+}
+
+fn is_hidden(overflow: &SideLength, rect: &Rect, threshold: Option<HideThreshold>) -> bool {
+    match threshold {
+        Some(HideThreshold::Ratio(ratio)) => 1.0 - get_visible_ratio(overflow, rect) > ratio,
+        Some(HideThreshold::Pixels(pixels)) => ALL_SIDES
+            .into_iter()
+            .any(|side| overflow.side(side) > pixels),
+        None => is_any_side_fully_clipped(&get_side_offsets(overflow.clone(), rect)),
+    }
+}
+
 /// Name of the [`Hide`] middleware.
 pub const HIDE_NAME: &str = "hide";
 
@@ -32,6 +57,27 @@ pub enum HideStrategy {
     #[default]
     ReferenceHidden,
     Escaped,
+    /// Evaluates both [`HideStrategy::ReferenceHidden`] and [`HideStrategy::Escaped`].
+    Both,
+}
+
+impl HideStrategy {
+    fn reference_hidden(self) -> bool {
+        matches!(self, HideStrategy::ReferenceHidden | HideStrategy::Both)
+    }
+
+    fn escaped(self) -> bool {
+        matches!(self, HideStrategy::Escaped | HideStrategy::Both)
+    }
+}
+
+/// Threshold used by [`Hide`] middleware to determine when an element is hidden.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HideThreshold {
+    /// Hidden when more than this ratio of the area of the element is clipped, between `0` and `1`.
+    Ratio(f64),
+    /// Hidden when more than this number of pixels of the element is clipped on any side.
+    Pixels(f64),
 }
 
 /// Options for [`Hide`] middleware.
@@ -46,6 +92,11 @@ pub struct HideOptions<Element: Clone> {
     ///
     /// Defaults to [`HideStrategy::ReferenceHidden`].
     pub strategy: Option<HideStrategy>,
+
+    /// The threshold used to determine when the reference or floating element is hidden.
+    ///
+    /// Defaults to hiding when any side of the element is fully clipped.
+    pub threshold: Option<HideThreshold>,
 }
 
 impl<Element: Clone> HideOptions<Element> {
@@ -60,6 +111,12 @@ impl<Element: Clone> HideOptions<Element> {
         self.strategy = Some(value);
         self
     }
+
+    /// Set `threshold` option.
+    pub fn threshold(mut self, value: HideThreshold) -> Self {
+        self.threshold = Some(value);
+        self
+    }
 }
 
 impl<Element: Clone> Default for HideOptions<Element> {
@@ -67,6 +124,7 @@ impl<Element: Clone> Default for HideOptions<Element> {
         Self {
             detect_overflow: Default::default(),
             strategy: Default::default(),
+            threshold: Default::default(),
         }
     }
 }
@@ -78,6 +136,12 @@ pub struct HideData {
     pub reference_hidden_offsets: Option<SideLength>,
     pub escaped: Option<bool>,
     pub escaped_offsets: Option<SideLength>,
+
+    /// The ratio of the area of the reference element that is visible, between `0` and `1`.
+    pub reference_visible_ratio: Option<f64>,
+
+    /// The ratio of the area of the floating element that is visible, between `0` and `1`.
+    pub floating_visible_ratio: Option<f64>,
 }
 
 /// Provides data to hide the floating element in applicable situations,
@@ -127,71 +191,46 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Hide<'a,
     fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
         let options = self.options.evaluate(state.clone());
 
-        let MiddlewareState {
-            elements, rects, ..
-        } = state;
+        let rects = state.rects;
 
         let strategy = options.strategy.unwrap_or_default();
+        let detect_overflow_options = options.detect_overflow.unwrap_or_default();
+
+        let mut data = HideData {
+            reference_hidden: None,
+            reference_hidden_offsets: None,
+            escaped: None,
+            escaped_offsets: None,
+            reference_visible_ratio: None,
+            floating_visible_ratio: None,
+        };
+
+        if strategy.reference_hidden() {
+            let overflow = detect_overflow(
+                state.clone(),
+                detect_overflow_options
+                    .clone()
+                    .element_context(ElementContext::Reference),
+            );
+
+            data.reference_hidden = Some(is_hidden(&overflow, &rects.reference, options.threshold));
+            data.reference_visible_ratio = Some(get_visible_ratio(&overflow, &rects.reference));
+            data.reference_hidden_offsets = Some(get_side_offsets(overflow, &rects.reference));
+        }
+
+        if strategy.escaped() {
+            let overflow = detect_overflow(state, detect_overflow_options.alt_boundary(true));
 
-        match strategy {
-            HideStrategy::ReferenceHidden => {
-                let overflow = detect_overflow(
-                    MiddlewareState {
-                        elements: elements.clone(),
-                        ..state
-                    },
-                    options
-                        .detect_overflow
-                        .unwrap_or_default()
-                        .element_context(ElementContext::Reference),
-                );
-
-                let offsets = get_side_offsets(overflow, &rects.reference);
-
-                MiddlewareReturn {
-                    x: None,
-                    y: None,
-                    data: Some(
-                        serde_json::to_value(HideData {
-                            reference_hidden: Some(is_any_side_fully_clipped(&offsets)),
-                            reference_hidden_offsets: Some(offsets),
-                            escaped: None,
-                            escaped_offsets: None,
-                        })
-                        .expect("Data should be valid JSON."),
-                    ),
-                    reset: None,
-                }
-            }
-            HideStrategy::Escaped => {
-                let overflow = detect_overflow(
-                    MiddlewareState {
-                        elements: elements.clone(),
-                        ..state
-                    },
-                    options
-                        .detect_overflow
-                        .unwrap_or_default()
-                        .alt_boundary(true),
-                );
-
-                let offsets = get_side_offsets(overflow, &rects.floating);
-
-                MiddlewareReturn {
-                    x: None,
-                    y: None,
-                    data: Some(
-                        serde_json::to_value(HideData {
-                            reference_hidden: None,
-                            reference_hidden_offsets: None,
-                            escaped: Some(is_any_side_fully_clipped(&offsets)),
-                            escaped_offsets: Some(offsets),
-                        })
-                        .expect("Data should be valid JSON."),
-                    ),
-                    reset: None,
-                }
-            }
+            data.escaped = Some(is_hidden(&overflow, &rects.floating, options.threshold));
+            data.floating_visible_ratio = Some(get_visible_ratio(&overflow, &rects.floating));
+            data.escaped_offsets = Some(get_side_offsets(overflow, &rects.floating));
+        }
+
+        MiddlewareReturn {
+            x: None,
+            y: None,
+            data: Some(serde_json::to_value(data).expect("Data should be valid JSON.")),
+            reset: None,
         }
     }
 }
@@ -203,3 +242,58 @@ impl<'a, Element: Clone, Window: Clone> MiddlewareWithOptions<Element, Window, H
         &self.options
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::Placement;
+
+    use crate::{
+        middleware::{Offset, OffsetOptions},
+        test_utils::{compute, config, Element, FLOATING, REFERENCE},
+    };
+
+    use super::*;
+
+    /// Reference hidden, escaped, reference visible ratio and floating visible ratio.
+    type Visibility = (Option<bool>, Option<bool>, Option<f64>, Option<f64>);
+
+    fn compute_data(options: HideOptions<Element>) -> Option<Visibility> {
+        compute(
+            &REFERENCE,
+            &FLOATING,
+            config().placement(Placement::Top).middleware(vec![
+                Box::new(Offset::new(OffsetOptions::Value(-25.0))),
+                Box::new(Hide::new(options)),
+            ]),
+        )
+        .middleware_data
+        .get_as::<HideData>(HIDE_NAME)
+        .map(|data| {
+            (
+                data.reference_hidden,
+                data.escaped,
+                data.reference_visible_ratio,
+                data.floating_visible_ratio,
+            )
+        })
+    }
+
+    #[test]
+    fn test_hide() {
+        let options = || HideOptions::default().strategy(HideStrategy::Both);
+
+        // Half of the floating element overflows the top of the clipping rect.
+        assert_eq!(
+            compute_data(options()),
+            Some((Some(false), Some(false), Some(1.0), Some(0.5)))
+        );
+        assert_eq!(
+            compute_data(options().threshold(HideThreshold::Ratio(0.4))),
+            Some((Some(false), Some(true), Some(1.0), Some(0.5)))
+        );
+        assert_eq!(
+            compute_data(options().threshold(HideThreshold::Pixels(30.0))),
+            Some((Some(false), Some(false), Some(1.0), Some(0.5)))
+        );
+    }
+}
@@ -0,0 +1,504 @@
+use serde::{Deserialize, Serialize};
+
+use floating_ui_utils::{ElementRects, Length, Rect, SideLength};
+
+use crate::{
+    detect_overflow::{detect_overflow, DetectOverflowOptions},
+    middleware::{Offset, OffsetOptions},
+    types::{
+        Derivable, DerivableFn, ElementContext, Middleware, MiddlewareReturn, MiddlewareState,
+        MiddlewareWithOptions,
+    },
+};
+
+/// Name of the [`Inner`] middleware.
+pub const INNER_NAME: &str = "inner";
+
+/// State passed to [`InnerOptions::apply`].
+#[derive(Clone)]
+pub struct InnerApplyState<'a, Element: Clone, Window: Clone> {
+    pub state: MiddlewareState<'a, Element, Window>,
+
+    /// The element that scrolls, either [`InnerOptions::scroll_element`] or the floating element.
+    pub scroll_element: &'a Element,
+
+    /// The maximum height of the scroll element, so the floating element does not overflow.
+    pub max_height: f64,
+
+    /// The scroll position of the scroll element, so the selected item stays aligned with the reference element.
+    pub scroll_top: f64,
+}
+
+pub type InnerApplyFn<Element, Window> = dyn Fn(InnerApplyState<Element, Window>);
+
+/// Options for [`Inner`] middleware.
+#[derive(Clone)]
+pub struct InnerOptions<'a, Element: Clone, Window: Clone> {
+    /// Options for [`detect_overflow`].
+    ///
+    /// Defaults to [`DetectOverflowOptions::default`].
+    pub detect_overflow: Option<DetectOverflowOptions<Element>>,
+
+    /// The item elements of the list.
+    pub list: Vec<Element>,
+
+    /// The index of the selected item, which is aligned with the reference element.
+    ///
+    /// Defaults to `0`.
+    pub index: Option<usize>,
+
+    /// The element that scrolls the list, if it is not the floating element itself.
+    ///
+    /// Defaults to [`Option::None`].
+    pub scroll_element: Option<Element>,
+
+    /// The offset of the list, e.g. changed by scrolling with [`use_inner_offset`](https://floating-ui.com/docs/inner#useinneroffset).
+    ///
+    /// Defaults to `0`.
+    pub offset: Option<f64>,
+
+    /// The minimum number of items that should be visible, otherwise [`InnerData::fallback`] is set.
+    ///
+    /// Defaults to `4`.
+    pub min_items_visible: Option<usize>,
+
+    /// The distance the reference element is allowed to overflow the clipping boundary before [`InnerData::fallback`] is set.
+    ///
+    /// Defaults to `0`.
+    pub reference_overflow_threshold: Option<f64>,
+
+    /// Function that is called to set the maximum height and scroll position of the scroll element.
+    pub apply: Option<&'a InnerApplyFn<Element, Window>>,
+}
+
+impl<'a, Element: Clone, Window: Clone> InnerOptions<'a, Element, Window> {
+    pub fn new(list: Vec<Element>) -> Self {
+        InnerOptions {
+            detect_overflow: None,
+            list,
+            index: None,
+            scroll_element: None,
+            offset: None,
+            min_items_visible: None,
+            reference_overflow_threshold: None,
+            apply: None,
+        }
+    }
+
+    /// Set `detect_overflow` option.
+    pub fn detect_overflow(mut self, value: DetectOverflowOptions<Element>) -> Self {
+        self.detect_overflow = Some(value);
+        self
+    }
+
+    /// Set `list` option.
+    pub fn list(mut self, value: Vec<Element>) -> Self {
+        self.list = value;
+        self
+    }
+
+    /// Set `index` option.
+    pub fn index(mut self, value: usize) -> Self {
+        self.index = Some(value);
+        self
+    }
+
+    /// Set `scroll_element` option.
+    pub fn scroll_element(mut self, value: Element) -> Self {
+        self.scroll_element = Some(value);
+        self
+    }
+
+    /// Set `offset` option.
+    pub fn offset(mut self, value: f64) -> Self {
+        self.offset = Some(value);
+        self
+    }
+
+    /// Set `min_items_visible` option.
+    pub fn min_items_visible(mut self, value: usize) -> Self {
+        self.min_items_visible = Some(value);
+        self
+    }
+
+    /// Set `reference_overflow_threshold` option.
+    pub fn reference_overflow_threshold(mut self, value: f64) -> Self {
+        self.reference_overflow_threshold = Some(value);
+        self
+    }
+
+    /// Set `apply` option.
+    pub fn apply(mut self, value: &'a InnerApplyFn<Element, Window>) -> Self {
+        self.apply = Some(value);
+        self
+    }
+}
+
+/// Data stored by [`Inner`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InnerData {
+    /// Whether there is not enough space to align the selected item with the reference element.
+    /// The floating element should fall back to standard anchored positioning.
+    pub fallback: bool,
+
+    pub max_height: f64,
+    pub scroll_top: f64,
+
+    /// The overflow of the floating element after positioning, used to scroll the list with the inner offset.
+    pub overflow: SideLength,
+}
+
+/// Positions the floating element over the reference element, so the selected item of a list is aligned with the reference element.
+/// This is the positioning of native `<select>` elements on macOS.
+///
+/// Shrinks and scrolls the list when it would overflow the clipping boundary.
+/// When there is not enough space, [`InnerData::fallback`] is set and standard anchored positioning should be used instead.
+///
+/// See <https://floating-ui.com/docs/inner> for the original documentation.
+pub struct Inner<'a, Element: Clone, Window: Clone> {
+    options: Derivable<'a, Element, Window, InnerOptions<'a, Element, Window>>,
+}
+
+impl<'a, Element: Clone, Window: Clone> Inner<'a, Element, Window> {
+    /// Constructs a new instance of this middleware.
+    pub fn new(options: InnerOptions<'a, Element, Window>) -> Self {
+        Inner {
+            options: options.into(),
+        }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options.
+    pub fn new_derivable(
+        options: Derivable<'a, Element, Window, InnerOptions<'a, Element, Window>>,
+    ) -> Self {
+        Inner { options }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options function.
+    pub fn new_derivable_fn(
+        options: DerivableFn<'a, Element, Window, InnerOptions<'a, Element, Window>>,
+    ) -> Self {
+        Inner {
+            options: options.into(),
+        }
+    }
+}
+
+impl<'a, Element: Clone, Window: Clone> Clone for Inner<'a, Element, Window> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
+
+fn with_floating_height(rects: &ElementRects, height: f64) -> ElementRects {
+    ElementRects {
+        reference: rects.reference.clone(),
+        floating: Rect {
+            height,
+            ..rects.floating.clone()
+        },
+    }
+}
+
+impl<'a, Element: Clone + PartialEq, Window: Clone> Middleware<Element, Window>
+    for Inner<'a, Element, Window>
+{
+    fn name(&self) -> &'static str {
+        INNER_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let options = self.options.evaluate(state.clone());
+
+        let MiddlewareState {
+            x,
+            y,
+            elements,
+            rects,
+            platform,
+            ..
+        } = state;
+
+        let empty = MiddlewareReturn {
+            x: None,
+            y: None,
+            data: None,
+            reset: None,
+        };
+
+        let Some(item) = options.list.get(options.index.unwrap_or(0)) else {
+            return empty;
+        };
+        let Some(item_rect) = platform.get_offset_rect(item) else {
+            return empty;
+        };
+
+        let floating = elements.floating;
+        let scroll_element = options.scroll_element.as_ref().unwrap_or(floating);
+        let floating_is_scroll_element = scroll_element == floating;
+        let detect_overflow_options = options.detect_overflow.unwrap_or_default();
+
+        let floating_client_top = platform
+            .get_client_offset(floating)
+            .map_or(0.0, |coords| coords.y);
+        let scroll_element_client_top = platform
+            .get_client_offset(scroll_element)
+            .map_or(0.0, |coords| coords.y);
+        let client_top = match floating_client_top != 0.0 {
+            true => floating_client_top,
+            false => scroll_element_client_top,
+        };
+        let floating_is_bordered = floating_client_top != 0.0;
+        let scroll_element_is_bordered = scroll_element_client_top != 0.0;
+
+        let scroll_height = platform
+            .get_scroll_length(scroll_element, Length::Height)
+            .unwrap_or(rects.floating.height);
+        let client_height = platform
+            .get_client_length(scroll_element, Length::Height)
+            .unwrap_or(scroll_height);
+
+        let offset = Offset::new(OffsetOptions::Value(
+            -item_rect.y
+                - floating_client_top
+                - rects.reference.height / 2.0
+                - item_rect.height / 2.0
+                - options.offset.unwrap_or(0.0),
+        ))
+        .compute(MiddlewareState {
+            elements: elements.clone(),
+            ..state
+        });
+        let next_x = offset.x.unwrap_or(x);
+        let next_y = offset.y.unwrap_or(y);
+
+        let scroll_rects =
+            with_floating_height(rects, scroll_height + client_top + floating_client_top);
+        let overflow = detect_overflow(
+            MiddlewareState {
+                x: next_x,
+                y: next_y,
+                elements: elements.clone(),
+                rects: &scroll_rects,
+                ..state
+            },
+            detect_overflow_options.clone(),
+        );
+        let reference_overflow = detect_overflow(
+            MiddlewareState {
+                x: next_x,
+                y: next_y,
+                elements: elements.clone(),
+                ..state
+            },
+            detect_overflow_options
+                .clone()
+                .element_context(ElementContext::Reference),
+        );
+
+        let diff_y = overflow.top.max(0.0);
+        let next_y = next_y + diff_y;
+        let is_scrollable = scroll_height > client_height;
+
+        let max_height = (scroll_height
+            + match (floating_is_bordered && floating_is_scroll_element)
+                || scroll_element_is_bordered
+            {
+                true => client_top * 2.0,
+                false => 0.0,
+            }
+            - diff_y
+            - overflow.bottom.max(0.0))
+        .max(0.0);
+        let max_height = match is_scrollable {
+            true => max_height,
+            false => max_height.round(),
+        };
+
+        if let Some(apply) = options.apply {
+            apply(InnerApplyState {
+                state: MiddlewareState {
+                    elements: elements.clone(),
+                    ..state
+                },
+                scroll_element,
+                max_height,
+                scroll_top: diff_y,
+            });
+        }
+
+        let scroll_element_height = platform
+            .get_offset_rect(scroll_element)
+            .map_or(max_height, |rect| rect.height);
+        let reference_overflow_threshold = options.reference_overflow_threshold.unwrap_or(0.0);
+        let min_items_visible = options
+            .min_items_visible
+            .unwrap_or(4)
+            .min(options.list.len());
+
+        let fallback = scroll_element_height < item_rect.height * min_items_visible as f64 - 1.0
+            || reference_overflow.top >= -reference_overflow_threshold
+            || reference_overflow.bottom >= -reference_overflow_threshold;
+
+        let next_rects = with_floating_height(
+            rects,
+            scroll_element_height + client_top + floating_client_top,
+        );
+        let next_overflow = detect_overflow(
+            MiddlewareState {
+                x: next_x,
+                y: next_y,
+                elements: elements.clone(),
+                rects: &next_rects,
+                ..state
+            },
+            detect_overflow_options,
+        );
+
+        MiddlewareReturn {
+            x: None,
+            y: Some(next_y),
+            data: Some(
+                serde_json::to_value(InnerData {
+                    fallback,
+                    max_height,
+                    scroll_top: diff_y,
+                    overflow: next_overflow,
+                })
+                .expect("Data should be valid JSON."),
+            ),
+            reset: None,
+        }
+    }
+}
+
+impl<'a, Element: Clone, Window: Clone>
+    MiddlewareWithOptions<Element, Window, InnerOptions<'a, Element, Window>>
+    for Inner<'a, Element, Window>
+{
+    fn options(&self) -> &Derivable<Element, Window, InnerOptions<'a, Element, Window>> {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::Placement;
+
+    use crate::test_utils::{compute, config, Element, Window, CLIPPING_RECT};
+
+    use super::*;
+
+    fn list() -> Vec<Element> {
+        (0..5)
+            .map(|index| Element::new(0.0, index as f64 * 20.0, 100.0, 20.0))
+            .collect()
+    }
+
+    fn compute_inner(
+        reference: &Element,
+        floating: &Element,
+        options: InnerOptions<'static, Element, Window>,
+    ) -> (f64, InnerData) {
+        let position = compute(
+            reference,
+            floating,
+            config()
+                .placement(Placement::Bottom)
+                .middleware(vec![Box::new(Inner::new(options))]),
+        );
+
+        (
+            position.y,
+            position
+                .middleware_data
+                .get_as(INNER_NAME)
+                .expect("Inner data should be set."),
+        )
+    }
+
+    #[test]
+    fn test_index_alignment() {
+        let reference = Element::new(0.0, 200.0, 100.0, 20.0);
+        let floating = Element::new(0.0, 0.0, 100.0, 100.0);
+
+        for index in [0, 2, 4] {
+            let (y, data) = compute_inner(
+                &reference,
+                &floating,
+                InnerOptions::new(list()).index(index),
+            );
+
+            assert_eq!(y + list()[index].rect.y, reference.rect.y);
+            assert!(!data.fallback);
+            assert_eq!((data.max_height, data.scroll_top), (100.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_min_items_visible() {
+        let reference = Element::new(0.0, 200.0, 100.0, 20.0);
+        let floating = Element::new(0.0, 0.0, 100.0, 60.0).scroll_height(100.0);
+
+        let (_, data) = compute_inner(&reference, &floating, InnerOptions::new(list()).index(2));
+        assert!(data.fallback);
+
+        let (_, data) = compute_inner(
+            &reference,
+            &floating,
+            InnerOptions::new(list()).index(2).min_items_visible(3),
+        );
+        assert!(!data.fallback);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let reference = Element::new(0.0, 20.0, 100.0, 20.0);
+        let floating = Element::new(0.0, 0.0, 100.0, 100.0);
+
+        let (y, data) = compute_inner(&reference, &floating, InnerOptions::new(list()).index(2));
+
+        // The list overflows the top of the clipping rect by 20, so it is moved down and shrunk.
+        assert_eq!(y, CLIPPING_RECT.y);
+        assert!(!data.fallback);
+        assert_eq!((data.max_height, data.scroll_top), (80.0, 20.0));
+    }
+
+    #[test]
+    fn test_reference_overflow_threshold() {
+        let reference = Element::new(0.0, 20.0, 100.0, 20.0);
+        let floating = Element::new(0.0, 0.0, 100.0, 100.0);
+
+        let (_, data) = compute_inner(&reference, &floating, InnerOptions::new(list()).index(2));
+        assert!(!data.fallback);
+
+        // The reference element is 20 away from the top of the clipping rect.
+        let (_, data) = compute_inner(
+            &reference,
+            &floating,
+            InnerOptions::new(list())
+                .index(2)
+                .reference_overflow_threshold(30.0),
+        );
+        assert!(data.fallback);
+    }
+
+    #[test]
+    fn test_floating_scroll_element() {
+        let reference = Element::new(0.0, 200.0, 100.0, 20.0);
+        let floating = Element::new(0.0, 0.0, 100.0, 60.0).scroll_height(100.0);
+
+        assert_eq!(
+            compute_inner(
+                &reference,
+                &floating,
+                InnerOptions::new(list())
+                    .index(2)
+                    .scroll_element(floating.clone()),
+            ),
+            compute_inner(&reference, &floating, InnerOptions::new(list()).index(2)),
+        );
+    }
+}
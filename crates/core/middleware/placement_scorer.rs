@@ -0,0 +1,62 @@
+use std::fmt::{self, Debug};
+
+use dyn_clone::DynClone;
+
+use floating_ui_utils::{get_side_axis, Axis, ElementRects, Placement};
+
+/// State passed to [`PlacementScorer::score`].
+#[derive(Clone, Debug)]
+pub struct PlacementScoreState<'a> {
+    /// The candidate placement.
+    pub placement: Placement,
+
+    /// The overflows of the candidate placement, on the sides checked by the middleware.
+    pub overflows: &'a [f64],
+
+    pub rects: &'a ElementRects,
+
+    /// The space available for the floating element on the side of the candidate placement.
+    pub available_space: f64,
+}
+
+impl<'a> PlacementScoreState<'a> {
+    pub(crate) fn new(
+        placement: Placement,
+        overflows: &'a [f64],
+        side_overflow: f64,
+        rects: &'a ElementRects,
+    ) -> Self {
+        let length = match get_side_axis(placement) {
+            Axis::X => rects.floating.width,
+            Axis::Y => rects.floating.height,
+        };
+
+        PlacementScoreState {
+            placement,
+            overflows,
+            rects,
+            available_space: length - side_overflow,
+        }
+    }
+}
+
+/// Scorer used by [`Flip`][`crate::middleware::Flip`] and [`AutoPlacement`][`crate::middleware::AutoPlacement`] middleware to rank candidate placements.
+///
+/// Lower scores are better. Implemented for closures, e.g. to penalise covering important regions or to prefer the side nearer the cursor.
+pub trait PlacementScorer: DynClone {
+    fn score(&self, state: PlacementScoreState) -> f64;
+}
+
+dyn_clone::clone_trait_object!(PlacementScorer);
+
+impl Debug for dyn PlacementScorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PlacementScorer")
+    }
+}
+
+impl<F: Fn(PlacementScoreState) -> f64 + Clone> PlacementScorer for F {
+    fn score(&self, state: PlacementScoreState) -> f64 {
+        self(state)
+    }
+}
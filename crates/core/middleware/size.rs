@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use floating_ui_utils::{Alignment, Axis, get_side_axis, Rect, Side};
 
 use crate::{
@@ -23,6 +25,24 @@ pub struct ApplyState<'a, Element: Clone, Window: Clone> {
 
 pub type ApplyFn<Element, Window> = dyn Fn(ApplyState<Element, Window>);
 
+/// Dimensions of the reference element matched by the floating element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatchReference {
+    Width,
+    Height,
+    Both,
+}
+
+impl MatchReference {
+    fn width(self) -> bool {
+        matches!(self, MatchReference::Width | MatchReference::Both)
+    }
+
+    fn height(self) -> bool {
+        matches!(self, MatchReference::Height | MatchReference::Both)
+    }
+}
+
 /// Options for [`Size`] middleware.
 #[derive(Clone)]
 pub struct SizeOptions<'a, Element: Clone, Window: Clone> {
@@ -33,6 +53,33 @@ pub struct SizeOptions<'a, Element: Clone, Window: Clone> {
 
     /// Function that is called to perform style mutations to the floating element to change its size.
     pub apply: Option<&'a ApplyFn<Element, Window>>,
+
+    /// Dimensions of the reference element the floating element should match.
+    ///
+    /// Defaults to `None`.
+    pub match_reference: Option<MatchReference>,
+
+    /// The minimum width of the floating element.
+    ///
+    /// Defaults to `None`.
+    pub min_width: Option<f64>,
+
+    /// The minimum height of the floating element.
+    ///
+    /// Defaults to `None`.
+    pub min_height: Option<f64>,
+
+    /// The maximum width of the floating element, the available width is used if it is smaller.
+    /// Use [`f64::INFINITY`] to only limit the width to the available width.
+    ///
+    /// Defaults to `None`.
+    pub max_width: Option<f64>,
+
+    /// The maximum height of the floating element, the available height is used if it is smaller.
+    /// Use [`f64::INFINITY`] to only limit the height to the available height.
+    ///
+    /// Defaults to `None`.
+    pub max_height: Option<f64>,
 }
 
 impl<'a, Element: Clone, Window: Clone> SizeOptions<'a, Element, Window> {
@@ -40,6 +87,11 @@ impl<'a, Element: Clone, Window: Clone> SizeOptions<'a, Element, Window> {
         SizeOptions {
             detect_overflow: None,
             apply: None,
+            match_reference: None,
+            min_width: None,
+            min_height: None,
+            max_width: None,
+            max_height: None,
         }
     }
 
@@ -54,6 +106,36 @@ impl<'a, Element: Clone, Window: Clone> SizeOptions<'a, Element, Window> {
         self.apply = Some(value);
         self
     }
+
+    /// Set `match_reference` option.
+    pub fn match_reference(mut self, value: MatchReference) -> Self {
+        self.match_reference = Some(value);
+        self
+    }
+
+    /// Set `min_width` option.
+    pub fn min_width(mut self, value: f64) -> Self {
+        self.min_width = Some(value);
+        self
+    }
+
+    /// Set `min_height` option.
+    pub fn min_height(mut self, value: f64) -> Self {
+        self.min_height = Some(value);
+        self
+    }
+
+    /// Set `max_width` option.
+    pub fn max_width(mut self, value: f64) -> Self {
+        self.max_width = Some(value);
+        self
+    }
+
+    /// Set `max_height` option.
+    pub fn max_height(mut self, value: f64) -> Self {
+        self.max_height = Some(value);
+        self
+    }
 }
 
 impl<'a, Element: Clone, Window: Clone> Default for SizeOptions<'a, Element, Window> {
@@ -61,10 +143,55 @@ impl<'a, Element: Clone, Window: Clone> Default for SizeOptions<'a, Element, Win
         Self {
             detect_overflow: Default::default(),
             apply: Default::default(),
+            match_reference: Default::default(),
+            min_width: Default::default(),
+            min_height: Default::default(),
+            max_width: Default::default(),
+            max_height: Default::default(),
         }
     }
 }
 
+/// Data stored by [`Size`] middleware.
+///
+/// The sizes are resolved from the declarative options of [`SizeOptions`] and are `None` if no option applies to them.
+/// They are applied to the floating element by [`Platform::apply_size`][`crate::Platform::apply_size`].
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SizeData {
+    pub available_width: f64,
+    pub available_height: f64,
+
+    /// The width of the reference element, if it is matched, clamped by the minimum and maximum width.
+    pub width: Option<f64>,
+
+    /// The height of the reference element, if it is matched, clamped by the minimum and maximum height.
+    pub height: Option<f64>,
+
+    pub min_width: Option<f64>,
+    pub min_height: Option<f64>,
+
+    /// The available width limited by the maximum width, but not below the minimum width.
+    pub max_width: Option<f64>,
+
+    /// The available height limited by the maximum height, but not below the minimum height.
+    pub max_height: Option<f64>,
+}
+
+fn resolve_length(
+    reference: Option<f64>,
+    available: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> (Option<f64>, Option<f64>) {
+    let min_length = min.unwrap_or(0.0);
+    let max_length = max.map(|max| available.min(max).max(min_length));
+
+    (
+        reference.map(|reference| reference.min(max.unwrap_or(f64::INFINITY)).max(min_length)),
+        max_length,
+    )
+}
+
 /// Provides data that allows you to change the size of the floating element -
 /// for instance, prevent it from overflowing the clipping boundary or match the width of the reference element.
 ///
@@ -209,6 +336,44 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Size<'a,
             }
         }
 
+        let match_reference = options.match_reference;
+        let (resolved_width, max_width) = resolve_length(
+            match_reference
+                .filter(|match_reference| match_reference.width())
+                .map(|_| rects.reference.width),
+            available_width,
+            options.min_width,
+            options.max_width,
+        );
+        let (resolved_height, max_height) = resolve_length(
+            match_reference
+                .filter(|match_reference| match_reference.height())
+                .map(|_| rects.reference.height),
+            available_height,
+            options.min_height,
+            options.max_height,
+        );
+        let data = SizeData {
+            available_width,
+            available_height,
+            width: resolved_width,
+            height: resolved_height,
+            min_width: options.min_width,
+            min_height: options.min_height,
+            max_width,
+            max_height,
+        };
+
+        // Styles set by `apply` or the app are left untouched when no declarative option is set.
+        if options.match_reference.is_some()
+            || options.min_width.is_some()
+            || options.min_height.is_some()
+            || options.max_width.is_some()
+            || options.max_height.is_some()
+        {
+            platform.apply_size(elements.floating, &data);
+        }
+
         if let Some(apply) = options.apply {
             apply(ApplyState {
                 state: MiddlewareState {
@@ -226,7 +391,7 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Size<'a,
             MiddlewareReturn {
                 x: None,
                 y: None,
-                data: None,
+                data: Some(serde_json::to_value(data).expect("Data should be valid JSON.")),
                 reset: Some(crate::Reset::Value(ResetValue {
                     placement: None,
                     rects: Some(ResetRects::True),
@@ -236,7 +401,7 @@ impl<'a, Element: Clone, Window: Clone> Middleware<Element, Window> for Size<'a,
             MiddlewareReturn {
                 x: None,
                 y: None,
-                data: None,
+                data: Some(serde_json::to_value(data).expect("Data should be valid JSON.")),
                 reset: None,
             }
         }
@@ -251,3 +416,39 @@ impl<'a, Element: Clone, Window: Clone>
         &self.options
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{compute, config, FLOATING, REFERENCE};
+
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        let middleware_data = compute(
+            &REFERENCE,
+            &FLOATING,
+            config().middleware(vec![Box::new(Size::new(
+                SizeOptions::default()
+                    .match_reference(MatchReference::Width)
+                    .min_width(120.0)
+                    .max_height(400.0),
+            ))]),
+        )
+        .middleware_data;
+
+        assert_eq!(
+            middleware_data.get_as::<SizeData>(SIZE_NAME),
+            Some(SizeData {
+                available_width: 100.0,
+                available_height: 900.0,
+                width: Some(120.0),
+                height: None,
+                min_width: Some(120.0),
+                min_height: None,
+                max_width: None,
+                max_height: Some(400.0),
+            })
+        );
+    }
+}
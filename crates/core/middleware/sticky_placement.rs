@@ -0,0 +1,54 @@
+use floating_ui_utils::Placement;
+
+/// Options to prefer the previous placement in [`Flip`][`crate::middleware::Flip`] and [`AutoPlacement`][`crate::middleware::AutoPlacement`] middleware,
+/// so the placement does not flicker between candidates while scrolling.
+///
+/// The previous placement is supplied through [`MiddlewareState::previous_placement`][`crate::MiddlewareState::previous_placement`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StickyPlacementOptions {
+    /// The overflow the previous placement is allowed to have on each checked side before another placement is chosen.
+    ///
+    /// Defaults to `0`.
+    pub threshold: Option<f64>,
+
+    /// The total overflow another placement has to improve on before it is chosen over the previous placement, when the previous placement exceeds the threshold.
+    ///
+    /// Defaults to [`Option::None`] (no margin).
+    pub margin: Option<f64>,
+}
+
+impl StickyPlacementOptions {
+    /// Set `threshold` option.
+    pub fn threshold(mut self, value: f64) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Set `margin` option.
+    pub fn margin(mut self, value: f64) -> Self {
+        self.margin = Some(value);
+        self
+    }
+
+    pub(crate) fn fits(&self, overflows: &[f64]) -> bool {
+        let threshold = self.threshold.unwrap_or(0.0);
+
+        overflows.iter().all(|overflow| *overflow <= threshold)
+    }
+
+    pub(crate) fn within_margin(&self, score: f64, best_score: f64) -> bool {
+        self.margin
+            .is_some_and(|margin| score <= best_score + margin)
+    }
+}
+
+/// The previous placement if sticky placement is enabled and it is one of the candidate placements.
+pub(crate) fn get_sticky_placement(
+    options: Option<&StickyPlacementOptions>,
+    previous_placement: Option<Placement>,
+    placements: &[Placement],
+) -> Option<Placement> {
+    options
+        .and(previous_placement)
+        .filter(|previous_placement| placements.contains(previous_placement))
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+
+use floating_ui_utils::{clamp, get_alignment_axis, get_axis_length, get_side, Axis, Coords, Side};
+
+use crate::{
+    middleware::arrow::{ArrowData, ARROW_NAME},
+    types::{Middleware, MiddlewareReturn, MiddlewareState},
+};
+
+/// Name of the [`TransformOrigin`] middleware.
+pub const TRANSFORM_ORIGIN_NAME: &str = "transformOrigin";
+
+/// Data stored by [`TransformOrigin`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransformOriginData {
+    /// The x-coord of the origin, relative to the floating element.
+    pub x: f64,
+
+    /// The y-coord of the origin, relative to the floating element.
+    pub y: f64,
+
+    /// The side of the floating element facing the reference element.
+    pub side: Side,
+}
+
+/// Provides the CSS `transform-origin` of the floating element, so it appears to scale out of the reference element.
+///
+/// The origin is on the edge of the reference element facing the floating element, aligned with the arrow tip if [`Arrow`][`crate::middleware::Arrow`] is used,
+/// or with the center of the reference element otherwise. Place it after [`Shift`][`crate::middleware::Shift`] and [`Arrow`][`crate::middleware::Arrow`].
+#[derive(Clone, Debug, Default)]
+pub struct TransformOrigin {}
+
+impl TransformOrigin {
+    /// Constructs a new instance of this middleware.
+    pub fn new() -> Self {
+        TransformOrigin {}
+    }
+}
+
+impl<Element: Clone, Window: Clone> Middleware<Element, Window> for TransformOrigin {
+    fn name(&self) -> &'static str {
+        TRANSFORM_ORIGIN_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let MiddlewareState {
+            x,
+            y,
+            placement,
+            middleware_data,
+            rects,
+            ..
+        } = state;
+
+        let coords = Coords { x, y };
+        let side = get_side(placement);
+        let alignment_axis = get_alignment_axis(placement);
+        let alignment_length = get_axis_length(alignment_axis);
+
+        let reference_center = rects.reference.axis(alignment_axis)
+            + rects.reference.length(alignment_length) / 2.0
+            - coords.axis(alignment_axis);
+        let alignment_origin = match middleware_data.get_as::<ArrowData>(ARROW_NAME) {
+            Some(arrow_data) => reference_center - arrow_data.center_offset,
+            None => clamp(
+                0.0,
+                reference_center,
+                rects.floating.length(alignment_length),
+            ),
+        };
+
+        let side_origin = match side {
+            Side::Top => rects.reference.y - y,
+            Side::Right => rects.reference.x + rects.reference.width - x,
+            Side::Bottom => rects.reference.y + rects.reference.height - y,
+            Side::Left => rects.reference.x - x,
+        };
+
+        let (origin_x, origin_y) = match alignment_axis {
+            Axis::X => (alignment_origin, side_origin),
+            Axis::Y => (side_origin, alignment_origin),
+        };
+
+        MiddlewareReturn {
+            x: None,
+            y: None,
+            data: Some(
+                serde_json::to_value(TransformOriginData {
+                    x: origin_x,
+                    y: origin_y,
+                    side: side.opposite(),
+                })
+                .expect("Data should be valid JSON."),
+            ),
+            reset: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::Placement;
+
+    use crate::test_utils::{compute, config, FLOATING, REFERENCE};
+
+    use super::*;
+
+    fn compute_data(placement: Placement) -> Option<(f64, f64, Side)> {
+        compute(
+            &REFERENCE,
+            &FLOATING,
+            config()
+                .placement(placement)
+                .middleware(vec![Box::new(TransformOrigin::new())]),
+        )
+        .middleware_data
+        .get_as::<TransformOriginData>(TRANSFORM_ORIGIN_NAME)
+        .map(|data| (data.x, data.y, data.side))
+    }
+
+    #[test]
+    fn test_transform_origin() {
+        assert_eq!(
+            compute_data(Placement::Bottom),
+            Some((25.0, 0.0, Side::Top))
+        );
+        assert_eq!(
+            compute_data(Placement::TopStart),
+            Some((50.0, 50.0, Side::Bottom))
+        );
+        assert_eq!(
+            compute_data(Placement::Right),
+            Some((0.0, 25.0, Side::Left))
+        );
+    }
+}
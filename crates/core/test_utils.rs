@@ -0,0 +1,137 @@
+use floating_ui_utils::{Dimensions, ElementOrVirtual, ElementRects, Length, Rect};
+
+use crate::{
+    compute_position::compute_position,
+    types::{
+        ComputePositionConfig, ComputePositionReturn, GetClippingRectArgs, GetElementRectsArgs,
+        Platform,
+    },
+};
+
+#[derive(Clone, Debug)]
+pub struct Element {
+    /// The offset rect of the element.
+    pub rect: Rect,
+
+    /// The scroll height of the element, which is larger than its height if it scrolls.
+    pub scroll_height: f64,
+}
+
+impl Element {
+    pub const fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Element {
+            rect: Rect {
+                x,
+                y,
+                width,
+                height,
+            },
+            scroll_height: height,
+        }
+    }
+
+    pub fn scroll_height(mut self, value: f64) -> Self {
+        self.scroll_height = value;
+        self
+    }
+}
+
+impl PartialEq for Element {
+    fn eq(&self, other: &Self) -> bool {
+        self.rect.x == other.rect.x
+            && self.rect.y == other.rect.y
+            && self.rect.width == other.rect.width
+            && self.rect.height == other.rect.height
+            && self.scroll_height == other.scroll_height
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Window {}
+
+pub const REFERENCE: Element = Element::new(0.0, 0.0, 100.0, 100.0);
+pub const FLOATING: Element = Element::new(0.0, 0.0, 50.0, 50.0);
+
+pub const CLIPPING_RECT: Rect = Rect {
+    x: 0.0,
+    y: 0.0,
+    width: 1000.0,
+    height: 1000.0,
+};
+
+/// Platform which reads the rects of the test elements.
+#[derive(Debug)]
+pub struct TestPlatform {
+    pub clipping_rect: Rect,
+}
+
+impl Platform<Element, Window> for TestPlatform {
+    fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+        let reference = match args.reference {
+            ElementOrVirtual::Element(element) => element.rect.clone(),
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                let client_rect = virtual_element.get_bounding_client_rect();
+
+                Rect {
+                    x: client_rect.x,
+                    y: client_rect.y,
+                    width: client_rect.width,
+                    height: client_rect.height,
+                }
+            }
+        };
+
+        ElementRects {
+            reference,
+            floating: Rect {
+                x: 0.0,
+                y: 0.0,
+                ..args.floating.rect.clone()
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, _args: GetClippingRectArgs<Element>) -> Rect {
+        self.clipping_rect.clone()
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        Dimensions {
+            width: element.rect.width,
+            height: element.rect.height,
+        }
+    }
+
+    fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
+        Some(element.rect.length(length))
+    }
+
+    fn get_offset_rect(&self, element: &Element) -> Option<Rect> {
+        Some(element.rect.clone())
+    }
+
+    fn get_scroll_length(&self, element: &Element, length: Length) -> Option<f64> {
+        Some(match length {
+            Length::Width => element.rect.width,
+            Length::Height => element.scroll_height,
+        })
+    }
+}
+
+pub const PLATFORM: TestPlatform = TestPlatform {
+    clipping_rect: CLIPPING_RECT,
+};
+
+/// Configuration with the test platform.
+pub fn config<'a>() -> ComputePositionConfig<'a, Element, Window> {
+    ComputePositionConfig::new(&PLATFORM)
+}
+
+/// Computes the position of `floating` next to `reference`.
+pub fn compute(
+    reference: &Element,
+    floating: &Element,
+    config: ComputePositionConfig<Element, Window>,
+) -> ComputePositionReturn {
+    compute_position(reference.into(), floating, config)
+}
@@ -91,6 +91,7 @@ fn test_returned_data() {
             placement: Some(Placement::Top),
             strategy: None,
             middleware: Some(vec![Box::new(CustomMiddleware {})]),
+            previous_placement: None,
         },
     );
 
@@ -135,6 +136,7 @@ fn test_middleware() {
             placement: None,
             strategy: None,
             middleware: None,
+            previous_placement: None,
         },
     );
 
@@ -146,6 +148,7 @@ fn test_middleware() {
             placement: None,
             strategy: None,
             middleware: Some(vec![Box::new(TestMiddleware {})]),
+            previous_placement: None,
         },
     );
 
@@ -182,6 +185,7 @@ fn test_middleware_data() {
             placement: None,
             strategy: None,
             middleware: Some(vec![Box::new(TestMiddleware {})]),
+            previous_placement: None,
         },
     );
 
@@ -9,6 +9,8 @@ use floating_ui_utils::{
     OwnedElementOrWindow, Placement, Rect, Strategy,
 };
 
+use crate::middleware::SizeData;
+
 pub type DerivableFn<'a, Element, Window, T> = &'a dyn Fn(MiddlewareState<Element, Window>) -> T;
 
 pub enum Derivable<'a, Element: Clone, Window: Clone, T: Clone> {
@@ -122,10 +124,27 @@ pub trait Platform<Element: Clone, Window: Clone>: Debug {
     fn get_client_length(&self, _element: &Element, _length: Length) -> Option<f64> {
         None
     }
+
+    fn get_client_offset(&self, _element: &Element) -> Option<Coords> {
+        None
+    }
+
+    fn get_offset_rect(&self, _element: &Element) -> Option<Rect> {
+        None
+    }
+
+    fn get_scroll_length(&self, _element: &Element, _length: Length) -> Option<f64> {
+        None
+    }
+
+    /// Applies the sizes resolved by [`Size`][`crate::middleware::Size`] middleware to the floating element.
+    ///
+    /// Sizes which are `None` are not controlled by an option and should be left untouched.
+    fn apply_size(&self, _element: &Element, _data: &SizeData) {}
 }
 
 /// Data stored by middleware.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct MiddlewareData {
     values: HashMap<String, serde_json::Value>,
 }
@@ -173,6 +192,11 @@ pub struct ComputePositionConfig<'a, Element, Window> {
     ///
     /// Defaults to an empty vector.
     pub middleware: Option<Vec<Box<dyn Middleware<Element, Window>>>>,
+
+    /// The placement chosen by the previous computation, used by middleware with sticky placement to keep it stable.
+    ///
+    /// Defaults to [`Option::None`].
+    pub previous_placement: Option<Placement>,
 }
 
 impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
@@ -182,6 +206,7 @@ impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
             placement: None,
             strategy: None,
             middleware: None,
+            previous_placement: None,
         }
     }
 
@@ -208,6 +233,12 @@ impl<'a, Element, Window> ComputePositionConfig<'a, Element, Window> {
         self.middleware = Some(value);
         self
     }
+
+    /// Set `previous_placement` option.
+    pub fn previous_placement(mut self, value: Placement) -> Self {
+        self.previous_placement = Some(value);
+        self
+    }
 }
 
 /// Return of [`compute_position`][crate::compute_position::compute_position].
@@ -302,6 +333,10 @@ pub struct MiddlewareState<'a, Element: Clone, Window: Clone> {
     pub y: f64,
     pub initial_placement: Placement,
     pub placement: Placement,
+
+    /// The placement chosen by the previous computation, see [`ComputePositionConfig::previous_placement`].
+    pub previous_placement: Option<Placement>,
+
     pub strategy: Strategy,
     pub middleware_data: &'a MiddlewareData,
     pub elements: Elements<'a, Element>,
@@ -316,6 +351,7 @@ impl<'a, Element: Clone, Window: Clone> Clone for MiddlewareState<'a, Element, W
             y: self.y,
             initial_placement: self.initial_placement,
             placement: self.placement,
+            previous_placement: self.previous_placement,
             strategy: self.strategy,
             middleware_data: self.middleware_data,
             elements: self.elements.clone(),
@@ -0,0 +1,25 @@
+#[doc(no_inline)]
+pub use floating_ui_dom::{
+    auto_update, client_rect, compute_position, dom, AlignedPlacement, Alignment, ApplyState,
+    ArrowData, AutoPlacement, AutoPlacementData, AutoPlacementDataOverflow, AutoPlacementOptions,
+    AutoUpdateOptions, Avoid, AvoidData, AvoidDataOverlap, AvoidOptions, Axis, Boundary,
+    ClientRect, ComputePositionConfig, ComputePositionReturn, Coords, DefaultLimiter,
+    DefaultVirtualElement, Derivable, DerivableFn, DetectOverflowOptions, Dimensions,
+    ElementContext, ElementOrVirtual, ElementRects, FallbackStrategy, Flip, FlipData,
+    FlipDataOverflow, FlipOptions, Hide, HideData, HideOptions, HideStrategy, HideThreshold,
+    Inline, InlineOptions, Inner, InnerApplyState, InnerData, InnerOptions, Length, LimitShift,
+    LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions, MatchReference, Middleware,
+    MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareVec, MiddlewareWithOptions,
+    Occlusion, OcclusionData, OcclusionOptions, Offset, OffsetData, OffsetOptions,
+    OffsetOptionsValues, Padding, Placement, PlacementScoreState, PlacementScorer, Rect,
+    RootBoundary, Shift, ShiftData, ShiftOptions, Side, Size, SizeData, SizeOptions,
+    StickyPlacementOptions, Strategy, TransformOrigin, TransformOriginData, VirtualElement,
+    ARROW_NAME, AUTO_PLACEMENT_NAME, AVOID_NAME, FLIP_NAME, HIDE_NAME, INLINE_NAME, INNER_NAME,
+    OCCLUSION_NAME, OFFSET_NAME, SHIFT_NAME, SIZE_NAME, TRANSFORM_ORIGIN_NAME,
+};
+pub use types::*;
+pub use use_floating::*;
+
+mod types;
+mod use_floating;
+mod utils;
@@ -0,0 +1,315 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::prelude::{
+    use_drop, use_effect, use_hook, use_memo, use_reactive, use_signal, MountedData,
+    ReadOnlySignal, Readable, Writable,
+};
+use web_sys::Element;
+
+use floating_ui_dom::{
+    compute_position, ComputePositionConfig, MiddlewareData, Placement, Strategy,
+};
+
+use crate::{
+    types::{
+        FloatingStyles, UseFloatingOptions, UseFloatingReturn, WhileElementsMountedCleanupFn,
+        WhileElementsMountedFn,
+    },
+    utils::{get_dpr::get_dpr, round_by_dpr::round_by_dpr},
+};
+
+/// The DOM element of a mounted element handle, as set by the `onmounted` event of the web renderer.
+pub fn get_element(mounted: Option<Rc<MountedData>>) -> Option<Element> {
+    mounted.and_then(|mounted| mounted.downcast::<Element>().cloned())
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a reference element.
+///
+/// The `reference` and `floating` signals hold the mounted element handles of the `onmounted` events.
+pub fn use_floating(
+    reference: ReadOnlySignal<Option<Rc<MountedData>>>,
+    floating: ReadOnlySignal<Option<Rc<MountedData>>>,
+    options: UseFloatingOptions,
+) -> UseFloatingReturn {
+    let UseFloatingOptions {
+        open: open_option,
+        placement: placement_option,
+        strategy: strategy_option,
+        middleware: middleware_option,
+        transform: transform_option,
+        while_elements_mounted: while_elements_mounted_option,
+    } = options;
+
+    let open_option = move || open_option.map(|open| open()).unwrap_or(true);
+    let placement_option_untracked = move || {
+        placement_option
+            .map(|placement| *placement.peek())
+            .unwrap_or(Placement::Bottom)
+    };
+    let strategy_option_untracked = move || {
+        strategy_option
+            .map(|strategy| *strategy.peek())
+            .unwrap_or(Strategy::Absolute)
+    };
+    let middleware_option_untracked =
+        move || middleware_option.map(|middleware| middleware.peek().clone());
+    let transform_option = move || {
+        transform_option
+            .map(|transform| transform())
+            .unwrap_or(true)
+    };
+
+    let x = use_signal(|| 0.0);
+    let y = use_signal(|| 0.0);
+    let strategy = use_signal(strategy_option_untracked);
+    let placement = use_signal(placement_option_untracked);
+    let middleware_data = use_signal(MiddlewareData::default);
+    let mut is_positioned = use_signal(|| false);
+
+    let floating_styles = use_memo(move || {
+        let initial_styles = FloatingStyles {
+            position: strategy(),
+            top: "0".into(),
+            left: "0".into(),
+            transform: None,
+            will_change: None,
+        };
+
+        if let Some(floating_element) = get_element(floating()) {
+            let x_val = round_by_dpr(&floating_element, x());
+            let y_val = round_by_dpr(&floating_element, y());
+
+            if transform_option() {
+                FloatingStyles {
+                    transform: Some(format!("translate({x_val}px, {y_val}px)")),
+                    will_change: match get_dpr(&floating_element) >= 1.5 {
+                        true => Some("transform".into()),
+                        false => None,
+                    },
+                    ..initial_styles
+                }
+            } else {
+                FloatingStyles {
+                    left: format!("{x_val}px"),
+                    top: format!("{y_val}px"),
+                    ..initial_styles
+                }
+            }
+        } else {
+            initial_styles
+        }
+    });
+    let style = use_memo(move || String::from(floating_styles()));
+
+    let update: Rc<dyn Fn()> = use_hook(|| {
+        Rc::new(move || {
+            let (mut x, mut y, mut strategy, mut placement, mut middleware_data, mut is_positioned) =
+                (x, y, strategy, placement, middleware_data, is_positioned);
+
+            if let (Some(reference_element), Some(floating_element)) = (
+                get_element(reference.peek().clone()),
+                get_element(floating.peek().clone()),
+            ) {
+                let config = ComputePositionConfig {
+                    placement: Some(placement_option_untracked()),
+                    strategy: Some(strategy_option_untracked()),
+                    middleware: middleware_option_untracked(),
+                    previous_placement: (*is_positioned.peek()).then(|| *placement.peek()),
+                };
+
+                let position =
+                    compute_position((&reference_element).into(), &floating_element, Some(config));
+                x.set(position.x);
+                y.set(position.y);
+                strategy.set(position.strategy);
+                placement.set(position.placement);
+                middleware_data.set(position.middleware_data);
+                is_positioned.set(true);
+            }
+        })
+    });
+
+    let has_while_elements_mounted = while_elements_mounted_option.is_some();
+    let while_elements_mounted: Rc<RefCell<Option<Rc<WhileElementsMountedFn>>>> =
+        use_hook(|| Rc::new(RefCell::new(None)));
+    while_elements_mounted.replace(while_elements_mounted_option);
+    let while_elements_mounted_cleanup: Rc<RefCell<Option<WhileElementsMountedCleanupFn>>> =
+        use_hook(|| Rc::new(RefCell::new(None)));
+
+    let cleanup = {
+        let while_elements_mounted_cleanup = while_elements_mounted_cleanup.clone();
+
+        move || {
+            if let Some(while_elements_mounted_cleanup) = while_elements_mounted_cleanup.take() {
+                while_elements_mounted_cleanup();
+            }
+        }
+    };
+
+    let attach = {
+        let update = update.clone();
+        let cleanup = cleanup.clone();
+
+        move || {
+            cleanup();
+
+            if let Some(while_elements_mounted) = while_elements_mounted.borrow().as_ref() {
+                if let (Some(reference_element), Some(floating_element)) = (
+                    get_element(reference.peek().clone()),
+                    get_element(floating.peek().clone()),
+                ) {
+                    while_elements_mounted_cleanup.replace(Some(while_elements_mounted(
+                        (&reference_element).into(),
+                        &floating_element,
+                        update.clone(),
+                    )));
+                }
+            } else {
+                update();
+            }
+        }
+    };
+
+    use_effect(use_reactive((&has_while_elements_mounted,), move |_| {
+        reference.read();
+        floating.read();
+
+        attach();
+    }));
+
+    {
+        let update = update.clone();
+
+        use_effect(move || {
+            if let Some(placement_option) = placement_option {
+                placement_option.read();
+            }
+            if let Some(strategy_option) = strategy_option {
+                strategy_option.read();
+            }
+            if let Some(middleware_option) = middleware_option {
+                middleware_option.read();
+            }
+
+            update();
+        });
+    }
+
+    use_effect(move || {
+        if !open_option() {
+            is_positioned.set(false);
+        }
+    });
+
+    use_drop(cleanup);
+
+    UseFloatingReturn {
+        x: x.into(),
+        y: y.into(),
+        placement: placement.into(),
+        strategy: strategy.into(),
+        middleware_data: middleware_data.into(),
+        is_positioned: is_positioned.into(),
+        floating_styles,
+        style,
+        update,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{any::Any, cell::Cell};
+
+    use dioxus::{
+        dioxus_core::{ScopeId, VNode, VirtualDom},
+        html::RenderedElementBacking,
+        prelude::Element as VNodeResult,
+    };
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Mounted element handle like the one of the web renderer.
+    struct TestElement(Element);
+
+    impl RenderedElementBacking for TestElement {
+        fn as_any(&self) -> &dyn Any {
+            &self.0
+        }
+    }
+
+    fn create_element() -> Option<Rc<MountedData>> {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("Window should have document.");
+        let element = document
+            .create_element("div")
+            .expect("Element should be created.");
+        document
+            .body()
+            .expect("Document should have body.")
+            .append_child(&element)
+            .expect("Element should be appended.");
+
+        Some(Rc::new(MountedData::new(TestElement(element))))
+    }
+
+    thread_local! {
+        static WITH_WHILE_ELEMENTS_MOUNTED: Cell<bool> = const { Cell::new(false) };
+        static EVENTS: RefCell<Vec<&'static str>> = const { RefCell::new(vec![]) };
+    }
+
+    fn push_event(event: &'static str) {
+        EVENTS.with(|events| events.borrow_mut().push(event));
+    }
+
+    fn take_events() -> Vec<&'static str> {
+        EVENTS.with(|events| events.take())
+    }
+
+    fn app() -> VNodeResult {
+        let reference = use_signal(create_element);
+        let floating = use_signal(create_element);
+
+        let mut options = UseFloatingOptions::default();
+        if WITH_WHILE_ELEMENTS_MOUNTED.get() {
+            options = options.while_elements_mounted(Rc::new(|_, _, update| {
+                push_event("attach");
+                update();
+
+                Box::new(|| push_event("cleanup"))
+            }));
+        }
+
+        use_floating(reference.into(), floating.into(), options);
+
+        VNode::empty()
+    }
+
+    fn render(dom: &mut VirtualDom, with_while_elements_mounted: bool) {
+        WITH_WHILE_ELEMENTS_MOUNTED.set(with_while_elements_mounted);
+        dom.mark_dirty(ScopeId::APP);
+        dom.render_immediate_to_vec();
+        dom.process_events();
+    }
+
+    #[wasm_bindgen_test]
+    fn attaches_when_while_elements_mounted_changes() {
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        dom.process_events();
+        assert!(take_events().is_empty());
+
+        render(&mut dom, true);
+        assert_eq!(take_events(), ["attach"]);
+
+        // The callback is recreated on every render, but the elements are only attached again when it is set or unset.
+        render(&mut dom, true);
+        assert!(take_events().is_empty());
+
+        render(&mut dom, false);
+        assert_eq!(take_events(), ["cleanup"]);
+    }
+}
@@ -53,6 +53,11 @@ pub struct ComputePositionConfig {
     ///
     /// Defaults to an empty vector.
     pub middleware: Option<MiddlewareVec>,
+
+    /// The placement chosen by the previous computation, used by middleware with sticky placement to keep it stable.
+    ///
+    /// Defaults to [`Option::None`].
+    pub previous_placement: Option<Placement>,
 }
 
 impl ComputePositionConfig {
@@ -73,6 +78,12 @@ impl ComputePositionConfig {
         self.middleware = Some(value);
         self
     }
+
+    /// Set `previous_placement` option.
+    pub fn previous_placement(mut self, value: Placement) -> Self {
+        self.previous_placement = Some(value);
+        self
+    }
 }
 
 /// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
@@ -93,6 +104,7 @@ pub fn compute_position(
             placement: config.placement,
             strategy: config.strategy,
             middleware: config.middleware,
+            previous_placement: config.previous_placement,
         },
     )
 }
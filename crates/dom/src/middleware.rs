@@ -1,18 +1,26 @@
 use web_sys::{Element, Window};
 
 use floating_ui_core::middleware::{
-    Arrow as CoreArrow, AutoPlacement as CoreAutoPlacement, Flip as CoreFlip, Hide as CoreHide,
-    Inline as CoreInline, Offset as CoreOffset, Shift as CoreShift, Size as CoreSize,
+    Arrow as CoreArrow, AutoPlacement as CoreAutoPlacement, Avoid as CoreAvoid, Flip as CoreFlip,
+    Hide as CoreHide, Inline as CoreInline, Inner as CoreInner, Offset as CoreOffset, Shift as CoreShift,
+    Size as CoreSize,
 };
 pub use floating_ui_core::middleware::{
     ApplyState, ARROW_NAME, ArrowData, ArrowOptions, AUTO_PLACEMENT_NAME,
-    AutoPlacementData, AutoPlacementDataOverflow, AutoPlacementOptions, DefaultLimiter, FallbackStrategy,
+    AutoPlacementData, AutoPlacementDataOverflow, AutoPlacementOptions, AVOID_NAME, AvoidData,
+    AvoidDataOverlap, AvoidOptions, DefaultLimiter, FallbackStrategy,
     FLIP_NAME, FlipData, FlipDataOverflow, FlipOptions, HIDE_NAME, HideData, HideOptions,
-    HideStrategy, INLINE_NAME, InlineOptions, LimitShift, LimitShiftOffset,
-    LimitShiftOffsetValues, LimitShiftOptions, OFFSET_NAME, OffsetData, OffsetOptions, OffsetOptionsValues, SHIFT_NAME,
-    ShiftData, ShiftOptions, SIZE_NAME, SizeOptions,
+    HideStrategy, HideThreshold, INLINE_NAME, InlineOptions, INNER_NAME, InnerApplyState, InnerData,
+    InnerOptions, LimitShift, LimitShiftOffset,
+    LimitShiftOffsetValues, LimitShiftOptions, MatchReference, OFFSET_NAME, OffsetData, OffsetOptions, OffsetOptionsValues, PlacementScoreState, PlacementScorer, SHIFT_NAME,
+    ShiftData, ShiftOptions, SIZE_NAME, SizeData, SizeOptions, StickyPlacementOptions,
+    TRANSFORM_ORIGIN_NAME, TransformOrigin, TransformOriginData,
 };
 
+pub use self::occlusion::*;
+
+mod occlusion;
+
 /// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
 ///
 /// See <https://floating-ui.com/docs/arrow> for the original documentation.
@@ -24,6 +32,10 @@ pub type Arrow<'a> = CoreArrow<'a, Element, Window>;
 /// See <https://floating-ui.com/docs/autoPlacement> for the original documentation.
 pub type AutoPlacement<'a> = CoreAutoPlacement<'a, Element, Window>;
 
+/// Avoids obstacles, such as other floating elements, by shifting the floating element along its alignment axis
+/// or by changing its placement, while respecting the clipping boundary.
+pub type Avoid<'a> = CoreAvoid<'a, Element, Window>;
+
 /// Optimizes the visibility of the floating element by flipping the `placement` in order to keep it in view when the preferred placement(s) will overflow the clipping boundary.
 /// Alternative to [`AutoPlacement`].
 ///
@@ -41,6 +53,12 @@ pub type Hide<'a> = CoreHide<'a, Element, Window>;
 /// See <https://floating-ui.com/docs/inline> for the original documentation.
 pub type Inline<'a> = CoreInline<'a, Element, Window>;
 
+/// Positions the floating element over the reference element, so the selected item of a list is aligned with the reference element.
+/// Useful for select-style listboxes.
+///
+/// See <https://floating-ui.com/docs/inner> for the original documentation.
+pub type Inner<'a> = CoreInner<'a, Element, Window>;
+
 /// Modifies the placement by translating the floating element along the specified axes.
 ///
 /// See <https://floating-ui.com/docs/offset> for the original documentation.
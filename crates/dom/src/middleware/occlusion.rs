@@ -0,0 +1,297 @@
+use serde::{Deserialize, Serialize};
+use web_sys::{wasm_bindgen::JsCast, Document, Element, Window};
+
+use floating_ui_core::{
+    Derivable, DerivableFn, Middleware, MiddlewareReturn, MiddlewareState, MiddlewareWithOptions,
+};
+use floating_ui_utils::ClientRect;
+
+use crate::{client_rect::to_client_rect, types::ElementOrVirtual};
+
+/// Returns whether the topmost element at the point covers the reference element,
+/// or `None` if the point is outside the viewport.
+fn is_point_occluded(
+    document: &Document,
+    reference: Option<&Element>,
+    floating: &Element,
+    x: f64,
+    y: f64,
+) -> Option<bool> {
+    let elements = document.elements_from_point(x as f32, y as f32);
+    if elements.length() == 0 {
+        return None;
+    }
+
+    let topmost = elements
+        .iter()
+        .filter_map(|element| element.dyn_into::<Element>().ok())
+        .find(|element| !floating.contains(Some(element)))?;
+
+    Some(match reference {
+        Some(reference) => {
+            !reference.contains(Some(&topmost)) && !topmost.contains(Some(reference))
+        }
+        None => false,
+    })
+}
+
+/// Name of the [`Occlusion`] middleware.
+pub const OCCLUSION_NAME: &str = "occlusion";
+
+/// Options for [`Occlusion`] middleware.
+#[derive(Clone, Debug, Default)]
+pub struct OcclusionOptions {
+    /// The number of sample columns of the grid over the reference element.
+    ///
+    /// Defaults to `3`.
+    pub columns: Option<u32>,
+
+    /// The number of sample rows of the grid over the reference element.
+    ///
+    /// Defaults to `3`.
+    pub rows: Option<u32>,
+}
+
+impl OcclusionOptions {
+    /// Set `columns` option.
+    pub fn columns(mut self, value: u32) -> Self {
+        self.columns = Some(value);
+        self
+    }
+
+    /// Set `rows` option.
+    pub fn rows(mut self, value: u32) -> Self {
+        self.rows = Some(value);
+        self
+    }
+}
+
+/// Data stored by [`Occlusion`] middleware.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OcclusionData {
+    /// Whether all samples of the reference element within the viewport are covered by other elements.
+    pub reference_occluded: bool,
+
+    /// The ratio of the samples of the reference element within the viewport that are covered by other elements, between `0` and `1`.
+    pub occluded_ratio: f64,
+}
+
+/// Provides data to hide the floating element when the reference element is covered by another element,
+/// such as a sticky header or a modal overlay.
+///
+/// Samples a grid of points over the reference element with `elementsFromPoint`, ignoring the floating element.
+/// Points outside the viewport are skipped, use [`Hide`][`crate::Hide`] to detect clipping.
+pub struct Occlusion<'a> {
+    options: Derivable<'a, Element, Window, OcclusionOptions>,
+}
+
+impl<'a> Occlusion<'a> {
+    /// Constructs a new instance of this middleware.
+    pub fn new(options: OcclusionOptions) -> Self {
+        Occlusion {
+            options: options.into(),
+        }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options.
+    pub fn new_derivable(options: Derivable<'a, Element, Window, OcclusionOptions>) -> Self {
+        Occlusion { options }
+    }
+
+    /// Constructs a new instance of this middleware with derivable options function.
+    pub fn new_derivable_fn(options: DerivableFn<'a, Element, Window, OcclusionOptions>) -> Self {
+        Occlusion {
+            options: options.into(),
+        }
+    }
+}
+
+impl<'a> Clone for Occlusion<'a> {
+    fn clone(&self) -> Self {
+        Self {
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl<'a> Middleware<Element, Window> for Occlusion<'a> {
+    fn name(&self) -> &'static str {
+        OCCLUSION_NAME
+    }
+
+    fn compute(&self, state: MiddlewareState<Element, Window>) -> MiddlewareReturn {
+        let options = self.options.evaluate(state.clone());
+
+        let MiddlewareState { elements, .. } = state;
+
+        let columns = options.columns.unwrap_or(3).max(1);
+        let rows = options.rows.unwrap_or(3).max(1);
+
+        let ClientRect {
+            x,
+            y,
+            width,
+            height,
+            ..
+        } = match &elements.reference {
+            ElementOrVirtual::Element(element) => {
+                to_client_rect(element.get_bounding_client_rect())
+            }
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                virtual_element.get_bounding_client_rect()
+            }
+        };
+        let reference = elements.reference.resolve();
+
+        // Without a document there is nothing to sample, so the reference element is reported as not occluded.
+        let samples: Vec<bool> = match elements.floating.owner_document() {
+            Some(document) => (0..rows)
+                .flat_map(|row| (0..columns).map(move |column| (row, column)))
+                .filter_map(|(row, column)| {
+                    is_point_occluded(
+                        &document,
+                        reference.as_ref(),
+                        elements.floating,
+                        x + width * (column as f64 + 0.5) / columns as f64,
+                        y + height * (row as f64 + 0.5) / rows as f64,
+                    )
+                })
+                .collect(),
+            None => vec![],
+        };
+
+        let occluded = samples.iter().filter(|occluded| **occluded).count();
+        let occluded_ratio = match samples.is_empty() {
+            true => 0.0,
+            false => occluded as f64 / samples.len() as f64,
+        };
+
+        MiddlewareReturn {
+            x: None,
+            y: None,
+            data: Some(
+                serde_json::to_value(OcclusionData {
+                    reference_occluded: !samples.is_empty() && occluded == samples.len(),
+                    occluded_ratio,
+                })
+                .expect("Data should be valid JSON."),
+            ),
+            reset: None,
+        }
+    }
+}
+
+impl<'a> MiddlewareWithOptions<Element, Window, OcclusionOptions> for Occlusion<'a> {
+    fn options(&self) -> &Derivable<'_, Element, Window, OcclusionOptions> {
+        &self.options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+
+    use crate::{compute_position, ComputePositionConfig};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_element(style: &str) -> Element {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("Window should have document.");
+        let element = document
+            .create_element("div")
+            .expect("Element should be created.");
+        element
+            .set_attribute(
+                "style",
+                &format!("position: fixed; top: 0; left: 0; width: 100px; height: 100px; {style}"),
+            )
+            .expect("Style should be set.");
+        document
+            .body()
+            .expect("Document should have body.")
+            .append_child(&element)
+            .expect("Element should be appended.");
+        element
+    }
+
+    fn compute_occlusion(reference: &Element, floating: &Element) -> OcclusionData {
+        compute_position(
+            reference.into(),
+            floating,
+            Some(
+                ComputePositionConfig::default()
+                    .middleware(vec![Box::new(Occlusion::new(OcclusionOptions::default()))]),
+            ),
+        )
+        .middleware_data
+        .get_as(OCCLUSION_NAME)
+        .expect("Occlusion data should be set.")
+    }
+
+    fn remove(elements: &[&Element]) {
+        for element in elements {
+            element.remove();
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn detects_occluded_reference() {
+        let reference = create_element("");
+        let floating = create_element("top: 200px;");
+        let overlay = create_element("z-index: 1;");
+
+        assert_eq!(
+            compute_occlusion(&reference, &floating),
+            OcclusionData {
+                reference_occluded: true,
+                occluded_ratio: 1.0,
+            }
+        );
+
+        remove(&[&reference, &floating, &overlay]);
+    }
+
+    #[wasm_bindgen_test]
+    fn detects_unoccluded_reference() {
+        let reference = create_element("");
+        let floating = create_element("top: 200px;");
+        let overlay = create_element("left: 60px; width: 40px; z-index: 1;");
+
+        // The overlay covers the right column of the sample grid.
+        let data = compute_occlusion(&reference, &floating);
+        assert!(!data.reference_occluded);
+        assert_eq!(data.occluded_ratio, 1.0 / 3.0);
+
+        overlay.remove();
+
+        assert_eq!(
+            compute_occlusion(&reference, &floating),
+            OcclusionData {
+                reference_occluded: false,
+                occluded_ratio: 0.0,
+            }
+        );
+
+        remove(&[&reference, &floating]);
+    }
+
+    #[wasm_bindgen_test]
+    fn ignores_floating_element() {
+        let reference = create_element("");
+        let floating = create_element("z-index: 1;");
+
+        assert_eq!(
+            compute_occlusion(&reference, &floating),
+            OcclusionData {
+                reference_occluded: false,
+                occluded_ratio: 0.0,
+            }
+        );
+
+        remove(&[&reference, &floating]);
+    }
+}
@@ -2,8 +2,8 @@ use web_sys::{Element, Window};
 
 use crate::dom::get_document_element;
 use floating_ui_core::{
-    ConvertOffsetParentRelativeRectToViewportRelativeRectArgs, GetClippingRectArgs,
-    GetElementRectsArgs, Platform as CorePlatform,
+    middleware::SizeData, ConvertOffsetParentRelativeRectToViewportRelativeRectArgs,
+    GetClippingRectArgs, GetElementRectsArgs, Platform as CorePlatform,
 };
 use floating_ui_utils::{
     ClientRect, Coords, Dimensions, ElementOrWindow, ElementRects, OwnedElementOrWindow, Rect,
@@ -11,24 +11,32 @@ use floating_ui_utils::{
 
 use crate::types::ElementOrVirtual;
 
+use self::apply_size::apply_size;
 use self::convert_offset_parent_relative_rect_to_viewport_relative_rect::convert_offset_parent_relative_rect_to_viewport_relative_rect;
 use self::get_client_length::get_client_length;
+use self::get_client_offset::get_client_offset;
 use self::get_client_rects::get_client_rects;
 use self::get_clipping_rect::get_clipping_rect;
 use self::get_dimensions::get_dimensions;
 use self::get_element_rects::get_element_rects;
 use self::get_offset_parent::get_offset_parent;
+use self::get_offset_rect::get_offset_rect;
 use self::get_scale::get_scale;
+use self::get_scroll_length::get_scroll_length;
 use self::is_rtl::is_rtl;
 
+pub mod apply_size;
 pub mod convert_offset_parent_relative_rect_to_viewport_relative_rect;
 pub mod get_client_length;
+pub mod get_client_offset;
 pub mod get_client_rects;
 pub mod get_clipping_rect;
 pub mod get_dimensions;
 pub mod get_element_rects;
 pub mod get_offset_parent;
+pub mod get_offset_rect;
 pub mod get_scale;
+pub mod get_scroll_length;
 pub mod is_rtl;
 
 #[derive(Debug)]
@@ -88,4 +96,24 @@ impl CorePlatform<Element, Window> for Platform {
     ) -> Option<f64> {
         Some(get_client_length(element, length))
     }
+
+    fn get_client_offset(&self, element: &Element) -> Option<Coords> {
+        Some(get_client_offset(element))
+    }
+
+    fn get_offset_rect(&self, element: &Element) -> Option<Rect> {
+        get_offset_rect(element)
+    }
+
+    fn get_scroll_length(
+        &self,
+        element: &Element,
+        length: floating_ui_utils::Length,
+    ) -> Option<f64> {
+        Some(get_scroll_length(element, length))
+    }
+
+    fn apply_size(&self, element: &Element, data: &SizeData) {
+        apply_size(element, data)
+    }
 }
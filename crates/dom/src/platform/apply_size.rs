@@ -0,0 +1,133 @@
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+
+use floating_ui_core::middleware::SizeData;
+
+pub fn apply_size(element: &Element, data: &SizeData) {
+    let Some(element) = element.dyn_ref::<HtmlElement>() else {
+        return;
+    };
+    let style = element.style();
+
+    for (property, value) in [
+        ("width", data.width),
+        ("height", data.height),
+        ("min-width", data.min_width),
+        ("min-height", data.min_height),
+        ("max-width", data.max_width),
+        ("max-height", data.max_height),
+    ] {
+        // Properties without a value are not controlled by an option, e.g. they are set by `apply` or the app.
+        if let Some(value) = value {
+            // Failing to update the style is not fatal, the position is still correct.
+            let _ = style.set_property(property, &format!("{}px", value.max(0.0)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::*;
+    use web_sys::Window;
+
+    use crate::{
+        compute_position, ApplyState, ComputePositionConfig, MatchReference, Size, SizeOptions,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn create_element(style: &str) -> HtmlElement {
+        let document = web_sys::window()
+            .and_then(|window| window.document())
+            .expect("Window should have document.");
+        let element = document
+            .create_element("div")
+            .expect("Element should be created.");
+        element
+            .set_attribute(
+                "style",
+                &format!("position: fixed; top: 0; left: 0; width: 100px; height: 100px; {style}"),
+            )
+            .expect("Style should be set.");
+        document
+            .body()
+            .expect("Document should have body.")
+            .append_child(&element)
+            .expect("Element should be appended.");
+        element.unchecked_into()
+    }
+
+    fn compute_size(
+        reference: &HtmlElement,
+        floating: &HtmlElement,
+        options: SizeOptions<'static, Element, Window>,
+    ) {
+        compute_position(
+            (&**reference).into(),
+            floating,
+            Some(ComputePositionConfig::default().middleware(vec![Box::new(Size::new(options))])),
+        );
+    }
+
+    fn style(element: &HtmlElement, property: &str) -> String {
+        element
+            .style()
+            .get_property_value(property)
+            .expect("Property should be read.")
+    }
+
+    fn apply(state: ApplyState<Element, Window>) {
+        let _ = state
+            .state
+            .elements
+            .floating
+            .unchecked_ref::<HtmlElement>()
+            .style()
+            .set_property("max-height", "50px");
+    }
+
+    #[wasm_bindgen_test]
+    fn keeps_styles_set_by_apply() {
+        let reference = create_element("width: 200px;");
+        let floating = create_element("");
+
+        compute_size(&reference, &floating, SizeOptions::default().apply(&apply));
+
+        assert_eq!(style(&floating, "max-height"), "50px");
+        assert_eq!(style(&floating, "width"), "100px");
+
+        // Only the properties controlled by an option are set.
+        compute_size(
+            &reference,
+            &floating,
+            SizeOptions::default()
+                .apply(&apply)
+                .match_reference(MatchReference::Width),
+        );
+
+        assert_eq!(style(&floating, "max-height"), "50px");
+        assert_eq!(style(&floating, "width"), "200px");
+
+        reference.remove();
+        floating.remove();
+    }
+
+    #[wasm_bindgen_test]
+    fn keeps_inline_styles_of_uncontrolled_properties() {
+        let reference = create_element("width: 200px;");
+        let floating = create_element("max-height: 50px;");
+
+        compute_size(
+            &reference,
+            &floating,
+            SizeOptions::default().match_reference(MatchReference::Width),
+        );
+
+        assert_eq!(style(&floating, "max-height"), "50px");
+        assert_eq!(style(&floating, "width"), "200px");
+
+        reference.remove();
+        floating.remove();
+    }
+}
@@ -0,0 +1,10 @@
+use web_sys::Element;
+
+use floating_ui_utils::Coords;
+
+pub fn get_client_offset(element: &Element) -> Coords {
+    Coords {
+        x: element.client_left() as f64,
+        y: element.client_top() as f64,
+    }
+}
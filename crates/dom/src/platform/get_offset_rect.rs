@@ -0,0 +1,12 @@
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+
+use floating_ui_utils::Rect;
+
+pub fn get_offset_rect(element: &Element) -> Option<Rect> {
+    element.dyn_ref::<HtmlElement>().map(|element| Rect {
+        x: element.offset_left() as f64,
+        y: element.offset_top() as f64,
+        width: element.offset_width() as f64,
+        height: element.offset_height() as f64,
+    })
+}
@@ -0,0 +1,10 @@
+use web_sys::Element;
+
+use floating_ui_utils::Length;
+
+pub fn get_scroll_length(element: &Element, length: Length) -> f64 {
+    match length {
+        Length::Width => element.scroll_width() as f64,
+        Length::Height => element.scroll_height() as f64,
+    }
+}
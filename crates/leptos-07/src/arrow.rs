@@ -0,0 +1,123 @@
+use std::marker::PhantomData;
+
+use leptos::html::ElementType;
+
+use floating_ui_dom::{
+    Arrow as CoreArrow, ArrowOptions as CoreArrowOptions, Middleware, MiddlewareReturn,
+    MiddlewareState, Padding, ARROW_NAME,
+};
+
+use crate::node_ref::NodeRefAsElement;
+
+/// Options for [`Arrow`].
+#[derive(Clone)]
+pub struct ArrowOptions<Ref, RefEl>
+where
+    Ref: NodeRefAsElement<RefEl>,
+    RefEl: ElementType + Clone,
+{
+    /// The arrow element to be positioned.
+    pub element: Ref,
+
+    /// The padding between the arrow element and the floating element edges.
+    /// Useful when the floating element has rounded corners.
+    ///
+    /// Defaults to `0` on all sides.
+    pub padding: Option<Padding>,
+
+    /// The offset of the arrow tip from the start of the arrow element along the alignment axis.
+    /// Useful for asymmetric arrow shapes, where the tip is not centered.
+    ///
+    /// Defaults to half the length of the arrow element.
+    pub tip_offset: Option<f64>,
+
+    phantom: PhantomData<RefEl>,
+}
+
+impl<Ref, RefEl> ArrowOptions<Ref, RefEl>
+where
+    Ref: NodeRefAsElement<RefEl>,
+    RefEl: ElementType + Clone,
+{
+    pub fn new(element: Ref) -> Self {
+        ArrowOptions {
+            element,
+            padding: None,
+            tip_offset: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Set `element` option.
+    pub fn element(mut self, value: Ref) -> Self {
+        self.element = value;
+        self
+    }
+
+    /// Set `padding` option.
+    pub fn padding(mut self, value: Padding) -> Self {
+        self.padding = Some(value);
+        self
+    }
+
+    /// Set `tip_offset` option.
+    pub fn tip_offset(mut self, value: f64) -> Self {
+        self.tip_offset = Some(value);
+        self
+    }
+}
+
+/// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
+///
+/// See <https://floating-ui.com/docs/arrow> for the original documentation.
+#[derive(Clone)]
+pub struct Arrow<Ref, RefEl>
+where
+    Ref: NodeRefAsElement<RefEl>,
+    RefEl: ElementType + Clone,
+{
+    options: ArrowOptions<Ref, RefEl>,
+}
+
+impl<Ref, RefEl> Arrow<Ref, RefEl>
+where
+    Ref: NodeRefAsElement<RefEl>,
+    RefEl: ElementType + Clone,
+{
+    pub fn new(options: ArrowOptions<Ref, RefEl>) -> Self {
+        Arrow { options }
+    }
+}
+
+impl<Ref, RefEl> Middleware<web_sys::Element, web_sys::Window> for Arrow<Ref, RefEl>
+where
+    Ref: NodeRefAsElement<RefEl>,
+    RefEl: ElementType + Clone,
+{
+    fn name(&self) -> &'static str {
+        ARROW_NAME
+    }
+
+    fn compute(
+        &self,
+        state: MiddlewareState<web_sys::Element, web_sys::Window>,
+    ) -> MiddlewareReturn {
+        let element = self.options.element.get_untracked_as_element();
+
+        if let Some(element) = element {
+            CoreArrow::new(CoreArrowOptions {
+                element,
+                padding: self.options.padding.clone(),
+                tip_offset: self.options.tip_offset,
+            })
+            .compute(state)
+        } else {
+            MiddlewareReturn {
+                x: None,
+                y: None,
+                data: None,
+                reset: None,
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use leptos::{
+    html::ElementType,
+    prelude::{Get, GetUntracked, NodeRef},
+    wasm_bindgen::JsCast,
+};
+use web_sys::Element;
+
+pub trait NodeRefAsElement<E: ElementType>: Copy + Send + Sync + 'static {
+    fn get_as_element(&self) -> Option<Element>;
+
+    fn get_untracked_as_element(&self) -> Option<Element>;
+}
+
+impl<E> NodeRefAsElement<E> for NodeRef<E>
+where
+    E: ElementType,
+    E::Output: JsCast + Clone + 'static,
+{
+    fn get_as_element(&self) -> Option<Element> {
+        self.get()
+            .map(|element| element.unchecked_into::<Element>())
+    }
+
+    fn get_untracked_as_element(&self) -> Option<Element> {
+        self.get_untracked()
+            .map(|element| element.unchecked_into::<Element>())
+    }
+}
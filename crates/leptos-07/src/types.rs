@@ -0,0 +1,245 @@
+use std::{rc::Rc, sync::Arc};
+
+use leptos::prelude::{Get, LocalStorage, MaybeProp, Signal};
+use web_sys::{Element, Window};
+
+use floating_ui_dom::{
+    auto_update, AutoUpdateOptions, ElementOrVirtual, Middleware, MiddlewareData, Placement,
+    Strategy,
+};
+
+pub type WhileElementsMountedFn =
+    dyn Fn(ElementOrVirtual, &Element, Rc<dyn Fn()>) -> WhileElementsMountedCleanupFn + Send + Sync;
+
+pub type WhileElementsMountedCleanupFn = Box<dyn Fn()>;
+
+/// Options for [`use_floating`][`crate::use_floating::use_floating`].
+#[derive(Clone, Default)]
+pub struct UseFloatingOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: MaybeProp<bool>,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: MaybeProp<Placement>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: MaybeProp<Strategy>,
+
+    /// Array of middleware objects to modify the positioning or provide data for rendering.
+    /// Middleware is not `Send`, so it is stored locally.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: MaybeProp<Vec<Box<dyn Middleware<Element, Window>>>, LocalStorage>,
+
+    ///  Whether to use `transform` for positioning instead of `top` and `left` in the `floatingStyles` object.
+    ///
+    /// Defaults to `true`.
+    pub transform: MaybeProp<bool>,
+
+    /// Callback to handle mounting/unmounting of the elements.
+    ///
+    /// Defaults to [`Option::None`].
+    pub while_elements_mounted: MaybeProp<Arc<WhileElementsMountedFn>>,
+}
+
+impl UseFloatingOptions {
+    /// Set `open` option.
+    pub fn open(mut self, value: MaybeProp<bool>) -> Self {
+        self.open = value;
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: MaybeProp<Placement>) -> Self {
+        self.placement = value;
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: MaybeProp<Strategy>) -> Self {
+        self.strategy = value;
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(
+        mut self,
+        value: MaybeProp<Vec<Box<dyn Middleware<Element, Window>>>, LocalStorage>,
+    ) -> Self {
+        self.middleware = value;
+        self
+    }
+
+    /// Set `transform` option.
+    pub fn transform(mut self, value: MaybeProp<bool>) -> Self {
+        self.transform = value;
+        self
+    }
+
+    /// Set `while_elements_mounted` option.
+    pub fn while_elements_mounted(mut self, value: MaybeProp<Arc<WhileElementsMountedFn>>) -> Self {
+        self.while_elements_mounted = value;
+        self
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with [`AutoUpdateOptions::default`].
+    pub fn while_elements_mounted_auto_update(self) -> Self {
+        let auto_update_arc: Arc<WhileElementsMountedFn> =
+            Arc::new(|reference, floating, update| {
+                auto_update(reference, floating, update, AutoUpdateOptions::default())
+            });
+        self.while_elements_mounted(auto_update_arc.into())
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with [`AutoUpdateOptions::default`] when `enabled` is `true`.
+    pub fn while_elements_mounted_auto_update_with_enabled(self, enabled: Signal<bool>) -> Self {
+        let auto_update_arc: Arc<WhileElementsMountedFn> =
+            Arc::new(|reference, floating, update| {
+                auto_update(reference, floating, update, AutoUpdateOptions::default())
+            });
+        self.while_elements_mounted(MaybeProp::derive(move || {
+            if enabled.get() {
+                Some(auto_update_arc.clone())
+            } else {
+                None
+            }
+        }))
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with `options`.
+    pub fn while_elements_mounted_auto_update_with_options(
+        self,
+        options: Signal<AutoUpdateOptions>,
+    ) -> Self {
+        let auto_update_arc = move |options: AutoUpdateOptions| -> Arc<WhileElementsMountedFn> {
+            Arc::new(move |reference, floating, update| {
+                auto_update(reference, floating, update, options.clone())
+            })
+        };
+
+        self.while_elements_mounted(MaybeProp::derive(move || {
+            Some(auto_update_arc(options.get()))
+        }))
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with `options` when `enabled` is `true`.
+    pub fn while_elements_mounted_auto_update_with_enabled_and_options(
+        self,
+        enabled: Signal<bool>,
+        options: Signal<AutoUpdateOptions>,
+    ) -> Self {
+        let auto_update_arc = move |options: AutoUpdateOptions| -> Arc<WhileElementsMountedFn> {
+            Arc::new(move |reference, floating, update| {
+                auto_update(reference, floating, update, options.clone())
+            })
+        };
+
+        self.while_elements_mounted(MaybeProp::derive(move || {
+            if enabled.get() {
+                Some(auto_update_arc(options.get()))
+            } else {
+                None
+            }
+        }))
+    }
+}
+
+/// CSS styles to apply to the floating element to position it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatingStyles {
+    pub position: Strategy,
+    pub top: String,
+    pub left: String,
+    pub transform: Option<String>,
+    pub will_change: Option<String>,
+    pub transform_origin: Option<String>,
+}
+
+impl FloatingStyles {
+    pub fn style_position(&self) -> String {
+        match self.position {
+            Strategy::Absolute => "absolute".into(),
+            Strategy::Fixed => "fixed".into(),
+        }
+    }
+
+    pub fn style_top(&self) -> String {
+        self.top.clone()
+    }
+
+    pub fn style_left(&self) -> String {
+        self.left.clone()
+    }
+
+    pub fn style_transform(&self) -> Option<String> {
+        self.transform.clone()
+    }
+
+    pub fn style_will_change(&self) -> Option<String> {
+        self.will_change.clone()
+    }
+
+    pub fn style_transform_origin(&self) -> Option<String> {
+        self.transform_origin.clone()
+    }
+}
+
+impl From<FloatingStyles> for String {
+    fn from(value: FloatingStyles) -> Self {
+        format!(
+            "position: {}; top: {}; left: {};{}{}{}",
+            match value.position {
+                Strategy::Absolute => "absolute",
+                Strategy::Fixed => "fixed",
+            },
+            value.top,
+            value.left,
+            value
+                .transform
+                .map_or("".into(), |transform| format!(" transform: {};", transform),),
+            value.will_change.map_or("".into(), |will_change| format!(
+                " will-change: {};",
+                will_change
+            )),
+            value
+                .transform_origin
+                .map_or("".into(), |transform_origin| format!(
+                    " transform-origin: {};",
+                    transform_origin
+                ))
+        )
+    }
+}
+
+/// Return of [`use_floating`][crate::use_floating::use_floating].
+pub struct UseFloatingReturn {
+    /// The x-coord of the floating element.
+    pub x: Signal<f64>,
+
+    /// The y-coord of the floating element.
+    pub y: Signal<f64>,
+
+    /// The stateful placement, which can be different from the initial `placement` passed as options.
+    pub placement: Signal<Placement>,
+
+    /// The strategy to use when positioning the floating element.
+    pub strategy: Signal<Strategy>,
+
+    /// Additional data from middleware.
+    pub middleware_data: Signal<MiddlewareData>,
+
+    /// Indicates if the floating element has been positioned.
+    pub is_positioned: Signal<bool>,
+
+    /// CSS styles to apply to the floating element to position it.
+    pub floating_styles: Signal<FloatingStyles>,
+
+    /// The function to update floating position manually.
+    pub update: Arc<dyn Fn() + Send + Sync>,
+}
@@ -0,0 +1,368 @@
+use std::{marker::PhantomData, rc::Rc, sync::Arc};
+
+use leptos::{
+    html::{Div, ElementType},
+    prelude::{
+        on_cleanup, signal, Effect, FromLocal, Get, GetUntracked, LocalStorage, MaybeProp, Memo,
+        NodeRef, Set, SetValue, StoredValue, UpdateValue, With,
+    },
+};
+
+use floating_ui_dom::{
+    compute_position, ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement,
+    Strategy, TransformOriginData, VirtualElement, TRANSFORM_ORIGIN_NAME,
+};
+
+use crate::{
+    node_ref::NodeRefAsElement,
+    types::{FloatingStyles, UseFloatingOptions, UseFloatingReturn, WhileElementsMountedCleanupFn},
+    utils::{get_dpr::get_dpr, round_by_dpr::round_by_dpr},
+};
+
+pub enum VirtualElementOrNodeRef<NodeRef, El>
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    VirtualElement(Box<dyn VirtualElement<web_sys::Element>>),
+    NodeRef(NodeRef, PhantomData<El>),
+}
+
+impl<NodeRef, El> VirtualElementOrNodeRef<NodeRef, El>
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    pub fn get(&self) -> Option<OwnedElementOrVirtual> {
+        match self {
+            VirtualElementOrNodeRef::VirtualElement(virtual_element) => {
+                Some(virtual_element.clone().into())
+            }
+            VirtualElementOrNodeRef::NodeRef(node_ref, _) => {
+                node_ref.get_as_element().map(|element| element.into())
+            }
+        }
+    }
+
+    pub fn get_untracked(&self) -> Option<OwnedElementOrVirtual> {
+        match self {
+            VirtualElementOrNodeRef::VirtualElement(virtual_element) => {
+                Some(virtual_element.clone().into())
+            }
+            VirtualElementOrNodeRef::NodeRef(node_ref, _) => node_ref
+                .get_untracked_as_element()
+                .map(|element| element.into()),
+        }
+    }
+}
+
+impl<NodeRef, El> Clone for VirtualElementOrNodeRef<NodeRef, El>
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::VirtualElement(virtual_element) => Self::VirtualElement(virtual_element.clone()),
+            Self::NodeRef(node_ref, phantom) => Self::NodeRef(*node_ref, *phantom),
+        }
+    }
+}
+
+impl From<Box<dyn VirtualElement<web_sys::Element>>>
+    for VirtualElementOrNodeRef<NodeRef<Div>, Div>
+{
+    fn from(value: Box<dyn VirtualElement<web_sys::Element>>) -> Self {
+        VirtualElementOrNodeRef::VirtualElement(value)
+    }
+}
+
+impl<NodeRef, El> From<NodeRef> for VirtualElementOrNodeRef<NodeRef, El>
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    fn from(value: NodeRef) -> Self {
+        VirtualElementOrNodeRef::NodeRef(value, PhantomData)
+    }
+}
+
+/// The reference is not `Send` when it is a virtual element, so it is stored locally.
+pub trait IntoReference<NodeRef, El>
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    fn into_reference(self) -> MaybeProp<VirtualElementOrNodeRef<NodeRef, El>, LocalStorage>;
+}
+
+impl IntoReference<NodeRef<Div>, Div> for Box<dyn VirtualElement<web_sys::Element>> {
+    fn into_reference(self) -> MaybeProp<VirtualElementOrNodeRef<NodeRef<Div>, Div>, LocalStorage> {
+        MaybeProp::from_local(VirtualElementOrNodeRef::VirtualElement(self))
+    }
+}
+
+impl<NodeRef, El> IntoReference<NodeRef, El> for NodeRef
+where
+    NodeRef: NodeRefAsElement<El>,
+    El: ElementType,
+{
+    fn into_reference(self) -> MaybeProp<VirtualElementOrNodeRef<NodeRef, El>, LocalStorage> {
+        MaybeProp::from_local(VirtualElementOrNodeRef::NodeRef(self, PhantomData))
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a reference element.
+pub fn use_floating<
+    Reference: NodeRefAsElement<ReferenceEl>,
+    ReferenceEl: ElementType,
+    Floating: NodeRefAsElement<FloatingEl>,
+    FloatingEl: ElementType,
+>(
+    reference: MaybeProp<VirtualElementOrNodeRef<Reference, ReferenceEl>, LocalStorage>,
+    floating: Floating,
+    options: UseFloatingOptions,
+) -> UseFloatingReturn {
+    let UseFloatingOptions {
+        open: open_option,
+        placement: placement_option,
+        strategy: strategy_option,
+        middleware: middleware_option,
+        transform: transform_option,
+        while_elements_mounted: while_elements_mounted_option,
+    } = options;
+
+    let open_option = move || open_option.get().unwrap_or(true);
+    let placement_option_untracked = move || {
+        placement_option
+            .get_untracked()
+            .unwrap_or(Placement::Bottom)
+    };
+    let strategy_option_untracked = move || {
+        strategy_option
+            .get_untracked()
+            .unwrap_or(Strategy::Absolute)
+    };
+    let middleware_option_untracked = move || middleware_option.get_untracked();
+    let transform_option = move || transform_option.get().unwrap_or(true);
+    let while_elements_mounted_untracked = move || while_elements_mounted_option.get_untracked();
+
+    let (x, set_x) = signal(0.0);
+    let (y, set_y) = signal(0.0);
+    let (strategy, set_strategy) = signal(strategy_option_untracked());
+    let (placement, set_placement) = signal(placement_option_untracked());
+    let (middleware_data, set_middleware_data) = signal(MiddlewareData::default());
+    let (is_positioned, set_is_positioned) = signal(false);
+    let floating_styles = Memo::new(move |_| {
+        let initial_styles = FloatingStyles {
+            position: strategy.get(),
+            top: "0".into(),
+            left: "0".into(),
+            transform: None,
+            will_change: None,
+            transform_origin: middleware_data
+                .with(|middleware_data| {
+                    middleware_data.get_as::<TransformOriginData>(TRANSFORM_ORIGIN_NAME)
+                })
+                .map(|data| format!("{}px {}px", data.x, data.y)),
+        };
+
+        if let Some(floating_element) = floating.get_as_element() {
+            let x_val = round_by_dpr(&floating_element, x.get());
+            let y_val = round_by_dpr(&floating_element, y.get());
+
+            if transform_option() {
+                FloatingStyles {
+                    transform: Some(format!("translate({x_val}px, {y_val}px)")),
+                    will_change: match get_dpr(&floating_element) >= 1.5 {
+                        true => Some("transform".into()),
+                        false => None,
+                    },
+                    ..initial_styles
+                }
+            } else {
+                FloatingStyles {
+                    left: format!("{x_val}px"),
+                    top: format!("{y_val}px"),
+                    ..initial_styles
+                }
+            }
+        } else {
+            initial_styles
+        }
+    });
+
+    let update = move || {
+        if let Some(reference) = reference.get_untracked() {
+            if let (Some(reference_element), Some(floating_element)) = (
+                reference.get_untracked(),
+                floating.get_untracked_as_element(),
+            ) {
+                let config = ComputePositionConfig {
+                    placement: Some(placement_option_untracked()),
+                    strategy: Some(strategy_option_untracked()),
+                    middleware: middleware_option_untracked(),
+                    previous_placement: is_positioned
+                        .get_untracked()
+                        .then(|| placement.get_untracked()),
+                };
+
+                let position =
+                    compute_position((&reference_element).into(), &floating_element, Some(config));
+                set_x.set(position.x);
+                set_y.set(position.y);
+                set_strategy.set(position.strategy);
+                set_placement.set(position.placement);
+                set_middleware_data.set(position.middleware_data);
+                set_is_positioned.set(true);
+            }
+        }
+    };
+
+    let while_elements_mounted_cleanup: StoredValue<
+        Option<WhileElementsMountedCleanupFn>,
+        LocalStorage,
+    > = StoredValue::new_local(None);
+
+    let cleanup = move || {
+        if let Some(while_elements_mounted_cleanup) = while_elements_mounted_cleanup
+            .try_update_value(|cleanup| cleanup.take())
+            .flatten()
+        {
+            while_elements_mounted_cleanup();
+        }
+    };
+
+    let attach = move || {
+        cleanup();
+
+        if let (Some(while_elements_mounted), Some(reference)) = (
+            while_elements_mounted_untracked(),
+            reference.get_untracked(),
+        ) {
+            if let (Some(reference_element), Some(floating_element)) = (
+                reference.get_untracked(),
+                floating.get_untracked_as_element(),
+            ) {
+                while_elements_mounted_cleanup.set_value(Some(while_elements_mounted(
+                    (&reference_element).into(),
+                    &floating_element,
+                    Rc::new(update),
+                )));
+            }
+        } else {
+            update();
+        }
+    };
+
+    let reset = move || {
+        if !open_option() {
+            set_is_positioned.set(false);
+        }
+    };
+
+    // Effects run after the elements are mounted, so the node refs are loaded at this point.
+    Effect::new(move |_| {
+        let is_reference_mounted = match reference.get() {
+            Some(VirtualElementOrNodeRef::VirtualElement(_)) => true,
+            Some(VirtualElementOrNodeRef::NodeRef(reference, _)) => {
+                reference.get_as_element().is_some()
+            }
+            None => false,
+        };
+        let is_floating_mounted = floating.get_as_element().is_some();
+
+        if is_reference_mounted && is_floating_mounted {
+            attach();
+        }
+    });
+
+    Effect::new(move |_| {
+        reset();
+    });
+
+    Effect::watch(
+        move || placement_option.get(),
+        move |_, _, _| {
+            update();
+        },
+        false,
+    );
+    Effect::watch(
+        move || strategy_option.get(),
+        move |_, _, _| {
+            update();
+        },
+        false,
+    );
+    Effect::watch(
+        move || middleware_option.get(),
+        move |_, _, _| {
+            update();
+        },
+        false,
+    );
+    Effect::watch(
+        move || while_elements_mounted_option.get(),
+        move |_, _, _| {
+            attach();
+        },
+        false,
+    );
+
+    on_cleanup(cleanup);
+
+    UseFloatingReturn {
+        x: x.into(),
+        y: y.into(),
+        placement: placement.into(),
+        strategy: strategy.into(),
+        middleware_data: middleware_data.into(),
+        is_positioned: is_positioned.into(),
+        floating_styles: floating_styles.into(),
+        update: Arc::new(update),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::{html::Div, prelude::*, task::tick};
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn updates_is_positioned_when_position_is_computed() {
+        #[component]
+        fn Component() -> impl IntoView {
+            let reference = NodeRef::<Div>::new();
+            let floating = NodeRef::<Div>::new();
+            let UseFloatingReturn { is_positioned, .. } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default(),
+            );
+
+            view! {
+                <div node_ref=reference />
+                <div node_ref=floating />
+                <div id="test-is-positioned">{is_positioned}</div>
+            }
+        }
+
+        mount_to_body(Component);
+
+        // Wait for the attach effect and the render effect of the text.
+        tick().await;
+        tick().await;
+
+        assert_eq!(
+            document()
+                .get_element_by_id("test-is-positioned")
+                .and_then(|element| element.text_content()),
+            Some("true".into())
+        );
+    }
+}
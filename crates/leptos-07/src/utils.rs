@@ -0,0 +1,2 @@
+pub mod get_dpr;
+pub mod round_by_dpr;
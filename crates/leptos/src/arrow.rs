@@ -25,6 +25,12 @@ where
     /// Defaults to `0` on all sides.
     pub padding: Option<Padding>,
 
+    /// The offset of the arrow tip from the start of the arrow element along the alignment axis.
+    /// Useful for asymmetric arrow shapes, where the tip is not centered.
+    ///
+    /// Defaults to half the length of the arrow element.
+    pub tip_offset: Option<f64>,
+
     phantom: PhantomData<RefEl>,
 }
 
@@ -37,6 +43,7 @@ where
         ArrowOptions {
             element,
             padding: None,
+            tip_offset: None,
             phantom: PhantomData,
         }
     }
@@ -52,6 +59,12 @@ where
         self.padding = Some(value);
         self
     }
+
+    /// Set `tip_offset` option.
+    pub fn tip_offset(mut self, value: f64) -> Self {
+        self.tip_offset = Some(value);
+        self
+    }
 }
 
 /// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
@@ -97,6 +110,7 @@ where
             CoreArrow::new(CoreArrowOptions {
                 element: element.clone(),
                 padding: self.options.padding.clone(),
+                tip_offset: self.options.tip_offset,
             })
             .compute(state)
         } else {
@@ -0,0 +1,408 @@
+use floating_ui_dom::{
+    dom::get_computed_style, Alignment, ArrowData, ShiftData, Side, ARROW_NAME, SHIFT_NAME,
+};
+use leptos::{
+    component, create_effect, create_signal, svg::Svg, view, Attribute, IntoView, MaybeProp,
+    NodeRef, Show, Signal, SignalGet, SignalSet, StoredValue,
+};
+
+use crate::{types::FloatingContext, utils::use_id::use_id};
+
+/// Renders a customizable SVG arrow that is rotated and positioned automatically from [`ArrowData`] and the placement of the floating element.
+///
+/// The [`Arrow`][crate::arrow::Arrow] middleware must be used with `node_ref` as its element.
+///
+/// See <https://floating-ui.com/docs/FloatingArrow> for the original documentation.
+#[component]
+pub fn FloatingArrow(
+    /// The context of the floating element.
+    context: FloatingContext,
+
+    /// The arrow element, to pass to the [`Arrow`][crate::arrow::Arrow] middleware.
+    #[prop(optional)]
+    node_ref: NodeRef<Svg>,
+
+    /// The width of the arrow.
+    ///
+    /// Defaults to `14`.
+    #[prop(into, optional)]
+    width: MaybeProp<f64>,
+
+    /// The height of the arrow.
+    ///
+    /// Defaults to `7`.
+    #[prop(into, optional)]
+    height: MaybeProp<f64>,
+
+    /// The radius of the rounded tip of the arrow.
+    ///
+    /// Defaults to `0` (sharp).
+    #[prop(into, optional)]
+    tip_radius: MaybeProp<f64>,
+
+    /// The color of the stroke.
+    ///
+    /// Defaults to [`Option::None`].
+    #[prop(into, optional)]
+    stroke: MaybeProp<String>,
+
+    /// The width of the stroke, matching the border width of the floating element.
+    ///
+    /// Defaults to `0`.
+    #[prop(into, optional)]
+    stroke_width: MaybeProp<f64>,
+
+    /// The color of the arrow, which should match the background of the floating element.
+    ///
+    /// Defaults to [`Option::None`].
+    #[prop(into, optional)]
+    fill: MaybeProp<String>,
+
+    /// A static offset of the arrow from the edge of the floating element, e.g. `"15%"` or `"10px"`.
+    /// Ignored when the floating element is shifted by [`Shift`][crate::Shift] middleware.
+    ///
+    /// Defaults to [`Option::None`], which positions the arrow from [`ArrowData`].
+    #[prop(into, optional)]
+    static_offset: MaybeProp<String>,
+
+    /// A custom path of the arrow, which points downwards.
+    ///
+    /// Defaults to [`Option::None`].
+    #[prop(into, optional)]
+    d: MaybeProp<String>,
+
+    /// Additional styles of the arrow.
+    ///
+    /// Defaults to [`Option::None`].
+    #[prop(into, optional)]
+    style: MaybeProp<String>,
+
+    #[prop(attrs)] attrs: Vec<(&'static str, Attribute)>,
+) -> impl IntoView {
+    let FloatingContext {
+        placement,
+        middleware_data,
+        floating,
+        ..
+    } = context;
+
+    let stroke = Signal::derive(move || stroke.get());
+    let fill = Signal::derive(move || fill.get());
+    let static_offset = Signal::derive(move || static_offset.get());
+    let d = Signal::derive(move || d.get());
+    let style = Signal::derive(move || style.get());
+
+    let clip_path_id = StoredValue::new(use_id());
+
+    let (is_rtl, set_is_rtl) = create_signal(false);
+    create_effect(move |_| {
+        if let Some(floating) = floating.get() {
+            set_is_rtl.set(
+                get_computed_style(&floating)
+                    .get_property_value("direction")
+                    .is_ok_and(|direction| direction == "rtl"),
+            );
+        }
+    });
+
+    let width = move || width.get().unwrap_or(14.0);
+    let height = move || height.get().unwrap_or(7.0);
+    let tip_radius = move || tip_radius.get().unwrap_or(0.0);
+    let is_custom_shape = move || d.get().is_some();
+
+    // Strokes must be double the border width, so the stroke width works as expected.
+    let computed_stroke_width = move || stroke_width.get().unwrap_or(0.0) * 2.0;
+
+    let computed_static_offset = Signal::derive(move || {
+        let side = placement.get().side();
+        let is_vertical_side = side == Side::Top || side == Side::Bottom;
+        let shift_data: Option<ShiftData> = middleware_data.get().get_as(SHIFT_NAME);
+
+        let is_shifted = shift_data.is_some_and(|shift_data| match is_vertical_side {
+            true => shift_data.x != 0.0,
+            false => shift_data.y != 0.0,
+        });
+
+        match is_shifted {
+            true => None,
+            false => static_offset.get(),
+        }
+    });
+
+    let d_value = move || {
+        d.get().unwrap_or_else(|| {
+            let width = width();
+            let height = height();
+            let svg_x = (width / 2.0) * (tip_radius() / -8.0 + 1.0);
+            let svg_y = ((height / 2.0) * tip_radius()) / 4.0;
+
+            format!(
+                "M0,0 H{} L{},{} Q{},{} {},{} Z",
+                width,
+                width - svg_x,
+                height - svg_y,
+                width / 2.0,
+                height,
+                svg_x,
+                height - svg_y
+            )
+        })
+    };
+
+    let svg_style = move || {
+        let placement = placement.get();
+        let side = placement.side();
+        let is_vertical_side = side == Side::Top || side == Side::Bottom;
+        let is_custom_shape = is_custom_shape();
+        let computed_static_offset = computed_static_offset.get();
+        let is_end = placement.alignment() == Some(Alignment::End);
+
+        let arrow_data: Option<ArrowData> = middleware_data.get().get_as(ARROW_NAME);
+        let (arrow_x, arrow_y) = arrow_data.map_or((None, None), |arrow_data| {
+            let offset = |value: Option<f64>| {
+                value.map(|value| {
+                    computed_static_offset
+                        .clone()
+                        .unwrap_or_else(|| format!("{}px", value))
+                })
+            };
+            (offset(arrow_data.x), offset(arrow_data.y))
+        });
+
+        let has_static_offset = computed_static_offset.is_some();
+        let y_offset_property = match has_static_offset && is_end {
+            true => "bottom",
+            false => "top",
+        };
+        let x_offset_property = match (has_static_offset, is_rtl.get()) {
+            (true, true) if is_end => "left",
+            (true, true) => "right",
+            (true, false) if is_end => "right",
+            _ => "left",
+        };
+
+        let side_property = match side {
+            Side::Top => "top",
+            Side::Right => "right",
+            Side::Bottom => "bottom",
+            Side::Left => "left",
+        };
+        let side_value = match is_vertical_side || is_custom_shape {
+            true => "100%".to_string(),
+            false => format!("calc(100% - {}px)", computed_stroke_width() / 2.0),
+        };
+
+        let rotation = match (side, is_custom_shape) {
+            (Side::Top, true) => Some("rotate(180deg)"),
+            (Side::Top, false) => None,
+            (Side::Left, true) => Some("rotate(90deg)"),
+            (Side::Left, false) => Some("rotate(-90deg)"),
+            (Side::Bottom, true) => None,
+            (Side::Bottom, false) => Some("rotate(180deg)"),
+            (Side::Right, true) => Some("rotate(-90deg)"),
+            (Side::Right, false) => Some("rotate(90deg)"),
+        };
+
+        // Properties without a value are omitted.
+        [
+            Some(("position", "absolute".into())),
+            Some(("pointer-events", "none".into())),
+            arrow_x.map(|arrow_x| (x_offset_property, arrow_x)),
+            arrow_y.map(|arrow_y| (y_offset_property, arrow_y)),
+            Some((side_property, side_value)),
+            rotation.map(|rotation| ("transform", rotation.into())),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(property, value)| format!("{}: {};", property, value))
+        .chain(style.get())
+        .collect::<Vec<_>>()
+        .join(" ")
+    };
+
+    let clip_path_url = move || clip_path_id.with_value(|id| format!("url(#{})", id));
+
+    let svg_width = move || match is_custom_shape() {
+        true => width(),
+        false => width() + computed_stroke_width(),
+    };
+    let view_box = move || format!("0 0 {} {}", width(), height().max(width()));
+    let clip_path_stroke_width = move || match is_custom_shape() {
+        true => computed_stroke_width(),
+        false => computed_stroke_width() + 1.0,
+    };
+    let path_stroke = move || match computed_stroke_width() > 0.0 && !is_custom_shape() {
+        true => fill.get(),
+        false => Some("none".into()),
+    };
+    let clip_rect_y = move || match is_custom_shape() {
+        true => -computed_stroke_width() / 2.0,
+        false => computed_stroke_width() / 2.0,
+    };
+
+    view! {
+        <Show when=move || floating.get().is_some()>
+            <svg
+                {..attrs.clone()}
+                _ref=node_ref
+                aria-hidden="true"
+                width=svg_width
+                height=width
+                viewBox=view_box
+                style=svg_style
+                fill=fill
+            >
+                <Show when=move || { computed_stroke_width() > 0.0 }>
+                    <path
+                        clip-path=clip_path_url
+                        fill="none"
+                        stroke=stroke
+                        stroke-width=clip_path_stroke_width
+                        d=d_value
+                    />
+                </Show>
+                <path stroke=path_stroke d=d_value />
+                <clipPath id=move || clip_path_id.get_value()>
+                    <rect
+                        x=move || -computed_stroke_width() / 2.0
+                        y=clip_rect_y
+                        width=move || width() + computed_stroke_width()
+                        height=width
+                    />
+                </clipPath>
+            </svg>
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_dom::{MiddlewareData, Placement};
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        test_utils::tick,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[component]
+    fn Component(
+        static_offset: ReadSignal<Option<String>>,
+        shift_x: ReadSignal<f64>,
+    ) -> impl IntoView {
+        let reference = create_node_ref::<Div>();
+        let floating = create_node_ref::<Div>();
+        let UseFloatingReturn { context, .. } = use_floating(
+            reference.into_reference(),
+            floating,
+            UseFloatingOptions::default(),
+        );
+
+        // The arrow can not be centered to the reference element.
+        let middleware_data = Signal::derive(move || {
+            let mut middleware_data = MiddlewareData::default();
+            middleware_data.set_as(
+                ARROW_NAME,
+                ArrowData {
+                    x: Some(20.0),
+                    y: None,
+                    center_offset: 5.0,
+                    alignment_offset: None,
+                    is_detached: false,
+                    side: Side::Top,
+                    rotation: 0.0,
+                },
+            );
+            middleware_data.set_as(
+                SHIFT_NAME,
+                ShiftData {
+                    x: shift_x.get(),
+                    y: 0.0,
+                },
+            );
+            middleware_data
+        });
+
+        let context = FloatingContext {
+            placement: Signal::derive(|| Placement::Bottom),
+            middleware_data,
+            ..context
+        };
+
+        view! {
+            <div _ref=reference />
+            <div _ref=floating>
+                <FloatingArrow
+                    context=context
+                    tip_radius=4.0
+                    static_offset=static_offset
+                    attr:id="test-arrow"
+                />
+            </div>
+        }
+    }
+
+    #[wasm_bindgen_test]
+    async fn renders_arrow_from_arrow_data() {
+        let (static_offset, set_static_offset) = create_signal(None);
+        let (shift_x, set_shift_x) = create_signal(0.0);
+
+        let document = document();
+        mount_to(document.body().unwrap(), move || {
+            view! { <Component static_offset=static_offset shift_x=shift_x /> }
+        });
+
+        tick().await;
+
+        let arrow = document
+            .get_element_by_id("test-arrow")
+            .expect("Arrow element should exist.");
+        let path = arrow
+            .query_selector("path")
+            .ok()
+            .flatten()
+            .expect("Path element should exist.");
+
+        assert_eq!(
+            path.get_attribute("d"),
+            Some("M0,0 H14 L10.5,3.5 Q7,7 3.5,3.5 Z".into())
+        );
+        assert_eq!(
+            arrow.get_attribute("style"),
+            Some(
+                "position: absolute; pointer-events: none; left: 20px; bottom: 100%; transform: rotate(180deg);"
+                    .into()
+            )
+        );
+
+        // The static offset is used even though the arrow is not centered.
+        set_static_offset.set(Some("15%".into()));
+        tick().await;
+
+        assert_eq!(
+            arrow.get_attribute("style"),
+            Some(
+                "position: absolute; pointer-events: none; left: 15%; bottom: 100%; transform: rotate(180deg);"
+                    .into()
+            )
+        );
+
+        // The static offset is ignored when the floating element is shifted.
+        set_shift_x.set(10.0);
+        tick().await;
+
+        assert_eq!(
+            arrow.get_attribute("style"),
+            Some(
+                "position: absolute; pointer-events: none; left: 20px; bottom: 100%; transform: rotate(180deg);"
+                    .into()
+            )
+        );
+    }
+}
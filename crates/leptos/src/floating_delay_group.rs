@@ -0,0 +1,320 @@
+use std::time::Duration;
+
+use leptos::{
+    component, create_effect, create_rw_signal, leptos_dom::helpers::TimeoutHandle, on_cleanup,
+    provide_context, set_timeout_with_handle, use_context, Children, IntoView, MaybeProp, RwSignal,
+    Signal, SignalGet, SignalGetUntracked, SignalSet, SignalWith, StoredValue,
+};
+
+use crate::{types::FloatingContext, utils::use_id::use_id};
+
+/// Open and close delays in milliseconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Delay {
+    pub open: u64,
+    pub close: u64,
+}
+
+impl From<u64> for Delay {
+    fn from(value: u64) -> Self {
+        Delay {
+            open: value,
+            close: value,
+        }
+    }
+}
+
+/// State shared by the members of a [`FloatingDelayGroup`].
+#[derive(Copy, Clone)]
+struct DelayGroupContext {
+    delay: RwSignal<Delay>,
+    initial_delay: Signal<Delay>,
+    timeout_ms: Signal<u64>,
+    current_id: RwSignal<Option<String>>,
+    is_instant_phase: RwSignal<bool>,
+}
+
+/// Groups floating elements such as tooltips, so that once one of them has opened, the others open instantly without the open delay.
+///
+/// See <https://floating-ui.com/docs/FloatingDelayGroup> for the original documentation.
+#[component]
+pub fn FloatingDelayGroup(
+    children: Children,
+
+    /// The delay of the group, used until one of its members has opened.
+    ///
+    /// Defaults to `0`.
+    #[prop(into, optional)]
+    delay: MaybeProp<Delay>,
+
+    /// Milliseconds after the last member closed at which the group delay resets to `delay`.
+    ///
+    /// Defaults to `0`.
+    #[prop(into, optional)]
+    timeout_ms: MaybeProp<u64>,
+) -> impl IntoView {
+    let initial_delay = Signal::derive(move || delay.get().unwrap_or_default());
+    let timeout_ms = Signal::derive(move || timeout_ms.get().unwrap_or(0));
+
+    let context = DelayGroupContext {
+        delay: create_rw_signal(initial_delay.get_untracked()),
+        initial_delay,
+        timeout_ms,
+        current_id: create_rw_signal(None),
+        is_instant_phase: create_rw_signal(false),
+    };
+
+    create_effect(move |_| {
+        context.delay.set(initial_delay.get());
+    });
+
+    // The group is in the instant phase once the current member changes to another member without closing in between.
+    let initial_current_id: StoredValue<Option<String>> = StoredValue::new(None);
+    create_effect(move |_| match context.current_id.get() {
+        Some(current_id) => {
+            if initial_current_id.with_value(Option::is_none) {
+                initial_current_id.set_value(Some(current_id));
+            } else if !context.is_instant_phase.get_untracked() {
+                context.is_instant_phase.set(true);
+            }
+        }
+        None => {
+            if context.is_instant_phase.get_untracked() {
+                context.is_instant_phase.set(false);
+            }
+            initial_current_id.set_value(None);
+        }
+    });
+
+    provide_context(context);
+
+    children()
+}
+
+/// Options for [`use_delay_group`].
+#[derive(Clone, Default)]
+pub struct UseDelayGroupOptions {
+    /// The ID of the floating element within the group.
+    ///
+    /// Defaults to a generated ID.
+    pub id: Option<String>,
+}
+
+impl UseDelayGroupOptions {
+    /// Set `id` option.
+    pub fn id(mut self, value: String) -> Self {
+        self.id = Some(value);
+        self
+    }
+}
+
+/// Return of [`use_delay_group`].
+pub struct UseDelayGroupReturn {
+    /// Whether the floating element is inside a [`FloatingDelayGroup`].
+    pub has_provider: bool,
+
+    /// The delay to use for opening and closing the floating element.
+    /// The open delay is `1` once another member of the group has opened.
+    pub delay: Signal<Delay>,
+
+    /// The ID of the member of the group that is currently open.
+    pub current_id: Signal<Option<String>>,
+
+    /// Whether the group is in the instant phase, i.e. a member opened while another member was open.
+    /// Useful to disable transitions in this phase.
+    pub is_instant_phase: Signal<bool>,
+}
+
+/// Enables the floating element to be a member of the nearest [`FloatingDelayGroup`].
+///
+/// See <https://floating-ui.com/docs/FloatingDelayGroup#usedelaygroup> for the original documentation.
+pub fn use_delay_group(
+    context: FloatingContext,
+    options: UseDelayGroupOptions,
+) -> UseDelayGroupReturn {
+    let FloatingContext {
+        open,
+        on_open_change,
+        ..
+    } = context;
+    let id = StoredValue::new(options.id.unwrap_or_else(use_id));
+
+    let Some(group) = use_context::<DelayGroupContext>() else {
+        return UseDelayGroupReturn {
+            has_provider: false,
+            delay: Signal::derive(Delay::default),
+            current_id: Signal::derive(|| None),
+            is_instant_phase: Signal::derive(|| false),
+        };
+    };
+
+    let is_current =
+        move |current_id: &Option<String>| id.with_value(|id| current_id.as_ref() == Some(id));
+
+    {
+        let on_open_change = on_open_change.clone();
+        create_effect(move |_| {
+            if group.current_id.with(Option::is_none) {
+                return;
+            }
+
+            group.delay.set(Delay {
+                open: 1,
+                close: group.initial_delay.get_untracked().close,
+            });
+
+            if !group.current_id.with(is_current) && open.get_untracked() {
+                on_open_change(false);
+            }
+        });
+    }
+
+    let timeout_handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+    let clear_timeout = move || {
+        if let Some(handle) = timeout_handle.get_value() {
+            handle.clear();
+        }
+        timeout_handle.set_value(None);
+    };
+
+    create_effect(move |_| {
+        clear_timeout();
+
+        if open.get() || !group.current_id.with(is_current) {
+            return;
+        }
+
+        let unset = {
+            let on_open_change = on_open_change.clone();
+            move || {
+                on_open_change(false);
+                group.delay.set(group.initial_delay.get_untracked());
+                group.current_id.set(None);
+            }
+        };
+
+        match group.timeout_ms.get_untracked() {
+            0 => unset(),
+            timeout_ms => timeout_handle
+                .set_value(set_timeout_with_handle(unset, Duration::from_millis(timeout_ms)).ok()),
+        }
+    });
+
+    on_cleanup(clear_timeout);
+
+    create_effect(move |_| {
+        if open.get() {
+            group.current_id.set(Some(id.get_value()));
+        }
+    });
+
+    UseDelayGroupReturn {
+        has_provider: true,
+        delay: group.delay.into(),
+        current_id: group.current_id.into(),
+        is_instant_phase: group.is_instant_phase.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        test_utils::tick,
+        types::OnOpenChangeFn,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[component]
+    fn Member(id: &'static str, open: RwSignal<bool>) -> impl IntoView {
+        let reference = create_node_ref::<Div>();
+        let floating = create_node_ref::<Div>();
+        let on_open_change: Rc<OnOpenChangeFn> = Rc::new(move |value| open.set(value));
+        let UseFloatingReturn { context, .. } = use_floating(
+            reference.into_reference(),
+            floating,
+            UseFloatingOptions::default()
+                .open(open.into())
+                .on_open_change(on_open_change.into()),
+        );
+
+        let UseDelayGroupReturn {
+            has_provider,
+            delay,
+            is_instant_phase,
+            ..
+        } = use_delay_group(context, UseDelayGroupOptions::default().id(id.into()));
+        assert!(has_provider);
+
+        view! {
+            <div _ref=reference id=format!("test-delay-{id}")>
+                {move || {
+                    let Delay { open, close } = delay.get();
+                    format!("{open}-{close}")
+                }}
+            </div>
+            <div _ref=floating id=format!("test-instant-{id}")>
+                {move || is_instant_phase.get().to_string()}
+            </div>
+        }
+    }
+
+    fn text(id: &str) -> String {
+        document()
+            .get_element_by_id(id)
+            .and_then(|element| element.text_content())
+            .expect("Element should exist.")
+    }
+
+    #[wasm_bindgen_test]
+    async fn shares_delay_between_members() {
+        let first_open = create_rw_signal(false);
+        let second_open = create_rw_signal(false);
+
+        mount_to(document().body().unwrap(), move || {
+            view! {
+                <FloatingDelayGroup delay=Delay::from(500)>
+                    <Member id="first" open=first_open />
+                    <Member id="second" open=second_open />
+                </FloatingDelayGroup>
+            }
+        });
+        tick().await;
+
+        assert_eq!(text("test-delay-first"), "500-500");
+        assert_eq!(text("test-delay-second"), "500-500");
+
+        // Once a member has opened, the other members open instantly.
+        first_open.set(true);
+        tick().await;
+
+        assert_eq!(text("test-delay-first"), "1-500");
+        assert_eq!(text("test-delay-second"), "1-500");
+        assert_eq!(text("test-instant-second"), "false");
+
+        // Opening another member closes the current member.
+        second_open.set(true);
+        tick().await;
+
+        assert!(!first_open.get_untracked());
+        assert_eq!(text("test-delay-second"), "1-500");
+        assert_eq!(text("test-instant-second"), "true");
+
+        // Closing the last member resets the delay of the group.
+        second_open.set(false);
+        tick().await;
+
+        assert_eq!(text("test-delay-first"), "500-500");
+        assert_eq!(text("test-delay-second"), "500-500");
+        assert_eq!(text("test-instant-second"), "false");
+    }
+}
@@ -1,24 +1,48 @@
 pub use arrow::*;
+pub use floating_arrow::*;
+pub use floating_delay_group::*;
 #[doc(no_inline)]
 pub use floating_ui_dom::{
     auto_update, client_rect, compute_position, dom, AlignedPlacement, Alignment, ApplyState,
     ArrowData, AutoPlacement, AutoPlacementData, AutoPlacementDataOverflow, AutoPlacementOptions,
-    AutoUpdateOptions, Axis, Boundary, ClientRect, ComputePositionConfig, ComputePositionReturn,
-    Coords, DefaultLimiter, DefaultVirtualElement, Derivable, DerivableFn, DetectOverflowOptions,
-    Dimensions, ElementContext, ElementOrVirtual, ElementRects, FallbackStrategy, Flip, FlipData,
-    FlipDataOverflow, FlipOptions, Hide, HideData, HideOptions, HideStrategy, Inline,
-    InlineOptions, Length, LimitShift, LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions,
-    Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareVec,
-    MiddlewareWithOptions, Offset, OffsetData, OffsetOptions, OffsetOptionsValues, Padding,
-    Placement, Rect, RootBoundary, Shift, ShiftData, ShiftOptions, Side, Size, SizeOptions,
-    Strategy, VirtualElement, ARROW_NAME, AUTO_PLACEMENT_NAME, FLIP_NAME, HIDE_NAME, INLINE_NAME,
-    OFFSET_NAME, SHIFT_NAME, SIZE_NAME,
+    AutoUpdateOptions, Avoid, AvoidData, AvoidDataOverlap, AvoidOptions, Axis, Boundary,
+    ClientRect, ComputePositionConfig, ComputePositionReturn, Coords, DefaultLimiter,
+    DefaultVirtualElement, Derivable, DerivableFn, DetectOverflowOptions, Dimensions,
+    ElementContext, ElementOrVirtual, ElementRects, FallbackStrategy, Flip, FlipData,
+    FlipDataOverflow, FlipOptions, Hide, HideData, HideOptions, HideStrategy, HideThreshold,
+    Inline, InlineOptions, Inner, InnerApplyState, InnerData, InnerOptions, Length, LimitShift,
+    LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions, MatchReference, Middleware,
+    MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareVec, MiddlewareWithOptions,
+    Occlusion, OcclusionData, OcclusionOptions, Offset, OffsetData, OffsetOptions,
+    OffsetOptionsValues, Padding, Placement, PlacementScoreState, PlacementScorer, Rect,
+    RootBoundary, Shift, ShiftData, ShiftOptions, Side, Size, SizeData, SizeOptions,
+    StickyPlacementOptions, Strategy, TransformOrigin, TransformOriginData, VirtualElement,
+    ARROW_NAME, AUTO_PLACEMENT_NAME, AVOID_NAME, FLIP_NAME, HIDE_NAME, INLINE_NAME, INNER_NAME,
+    OCCLUSION_NAME, OFFSET_NAME, SHIFT_NAME, SIZE_NAME, TRANSFORM_ORIGIN_NAME,
 };
 pub use types::*;
+pub use use_client_point::*;
 pub use use_floating::*;
+pub use use_inner_offset::*;
+pub use use_interactions::*;
+pub use use_list_navigation::*;
+pub use use_role::*;
+pub use use_transition::*;
+pub use use_typeahead::*;
 
 mod arrow;
+mod floating_arrow;
+mod floating_delay_group;
 mod node_ref;
+#[cfg(test)]
+mod test_utils;
 mod types;
+mod use_client_point;
 mod use_floating;
+mod use_inner_offset;
+mod use_interactions;
+mod use_list_navigation;
+mod use_role;
+mod use_transition;
+mod use_typeahead;
 mod utils;
@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use leptos::window;
+use wasm_bindgen_futures::{js_sys::Promise, JsFuture};
+
+/// Waits for the next task, so pending effects have run.
+pub async fn tick() {
+    sleep(Duration::ZERO).await;
+}
+
+/// Waits for the duration, e.g. until a timeout has fired.
+pub async fn sleep(duration: Duration) {
+    let promise = Promise::new(&mut |resolve, _| {
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                &resolve,
+                duration.as_millis() as i32,
+            )
+            .expect("Timeout should be set.");
+    });
+
+    JsFuture::from(promise)
+        .await
+        .expect("Timeout should be resolved.");
+}
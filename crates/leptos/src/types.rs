@@ -1,11 +1,14 @@
 use std::rc::Rc;
 
-use leptos::{Attribute, IntoAttribute, MaybeProp, MaybeSignal, Signal, SignalGet};
+use leptos::{
+    html::Binding, Attribute, IntoAttribute, MaybeProp, MaybeSignal, Signal, SignalGet, SignalSet,
+    WriteSignal,
+};
 use web_sys::{Element, Window};
 
 use floating_ui_dom::{
     auto_update, AutoUpdateOptions, ElementOrVirtual, Middleware, MiddlewareData, Placement,
-    Strategy,
+    Strategy, VirtualElement,
 };
 
 pub type WhileElementsMountedFn =
@@ -13,6 +16,8 @@ pub type WhileElementsMountedFn =
 
 pub type WhileElementsMountedCleanupFn = Box<dyn Fn()>;
 
+pub type OnOpenChangeFn = dyn Fn(bool);
+
 /// Options for [`use_floating`][`crate::use_floating::use_floating`].
 #[derive(Clone, Default)]
 pub struct UseFloatingOptions {
@@ -45,6 +50,17 @@ pub struct UseFloatingOptions {
     ///
     /// Defaults to [`Option::None`].
     pub while_elements_mounted: MaybeProp<Rc<WhileElementsMountedFn>>,
+
+    /// Callback to change the open/close state of the floating element, used by interaction hooks.
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_open_change: MaybeProp<Rc<OnOpenChangeFn>>,
+
+    /// Virtual element to position the floating element against, instead of the reference element.
+    /// Interactions and [`auto_update`] still use the reference element.
+    ///
+    /// Defaults to [`Option::None`].
+    pub position_reference: MaybeProp<Box<dyn VirtualElement<Element>>>,
 }
 
 impl UseFloatingOptions {
@@ -87,6 +103,21 @@ impl UseFloatingOptions {
         self
     }
 
+    /// Set `on_open_change` option.
+    pub fn on_open_change(mut self, value: MaybeProp<Rc<OnOpenChangeFn>>) -> Self {
+        self.on_open_change = value;
+        self
+    }
+
+    /// Set `position_reference` option.
+    pub fn position_reference(
+        mut self,
+        value: MaybeProp<Box<dyn VirtualElement<Element>>>,
+    ) -> Self {
+        self.position_reference = value;
+        self
+    }
+
     /// Set `while_elements_mounted` option to [`auto_update`] with [`AutoUpdateOptions::default`].
     pub fn while_elements_mounted_auto_update(self) -> Self {
         let auto_update_rc: Rc<WhileElementsMountedFn> = Rc::new(|reference, floating, update| {
@@ -158,6 +189,8 @@ pub struct FloatingStyles {
     pub left: String,
     pub transform: Option<String>,
     pub will_change: Option<String>,
+    pub visibility: Option<String>,
+    pub transform_origin: Option<String>,
 }
 
 impl FloatingStyles {
@@ -183,12 +216,20 @@ impl FloatingStyles {
     pub fn style_will_change(&self) -> Option<String> {
         self.will_change.clone()
     }
+
+    pub fn style_visibility(&self) -> Option<String> {
+        self.visibility.clone()
+    }
+
+    pub fn style_transform_origin(&self) -> Option<String> {
+        self.transform_origin.clone()
+    }
 }
 
 impl From<FloatingStyles> for String {
     fn from(value: FloatingStyles) -> Self {
         format!(
-            "position: {}; top: {}; left: {};{}{}",
+            "position: {}; top: {}; left: {};{}{}{}{}",
             match value.position {
                 Strategy::Absolute => "absolute",
                 Strategy::Fixed => "fixed",
@@ -201,7 +242,17 @@ impl From<FloatingStyles> for String {
             value.will_change.map_or("".into(), |will_change| format!(
                 " will-change: {};",
                 will_change
-            ))
+            )),
+            value.visibility.map_or("".into(), |visibility| format!(
+                " visibility: {};",
+                visibility
+            )),
+            value
+                .transform_origin
+                .map_or("".into(), |transform_origin| format!(
+                    " transform-origin: {};",
+                    transform_origin
+                ))
         )
     }
 }
@@ -242,4 +293,95 @@ pub struct UseFloatingReturn {
 
     /// The function to update floating position manually.
     pub update: Rc<dyn Fn()>,
+
+    /// Callback refs to set the elements dynamically.
+    pub refs: UseFloatingRefs,
+
+    /// Context of the floating element, passed to interaction hooks.
+    pub context: FloatingContext,
+}
+
+/// Context of a floating element, passed to interaction hooks such as [`use_list_navigation`][crate::use_list_navigation::use_list_navigation].
+#[derive(Clone)]
+pub struct FloatingContext {
+    /// Represents the open/close state of the floating element.
+    pub open: Signal<bool>,
+
+    /// Changes the open/close state of the floating element, see [`UseFloatingOptions::on_open_change`].
+    pub on_open_change: Rc<OnOpenChangeFn>,
+
+    /// The stateful placement of the floating element.
+    pub placement: Signal<Placement>,
+
+    /// The strategy used to position the floating element.
+    pub strategy: Signal<Strategy>,
+
+    /// Additional data from middleware.
+    pub middleware_data: Signal<MiddlewareData>,
+
+    /// The reference element, or the context element of a virtual reference.
+    pub reference: Signal<Option<Element>>,
+
+    /// The floating element.
+    pub floating: Signal<Option<Element>>,
+
+    /// Callback refs to set the elements dynamically.
+    pub refs: UseFloatingRefs,
+}
+
+/// Callback refs of [`use_floating`][crate::use_floating::use_floating].
+///
+/// Elements set through these refs take precedence over the node refs passed to `use_floating`.
+/// This allows using elements which can not be bound with a node ref, such as SVG or custom elements.
+#[derive(Clone, Copy)]
+pub struct UseFloatingRefs {
+    pub(crate) set_reference: WriteSignal<Option<Element>>,
+    pub(crate) set_floating: WriteSignal<Option<Element>>,
+    pub(crate) set_position_reference: WriteSignal<Option<Box<dyn VirtualElement<Element>>>>,
+}
+
+impl UseFloatingRefs {
+    /// Set the reference element, used for both positioning and interactions.
+    pub fn set_reference(&self, element: Option<Element>) {
+        self.set_reference.set(element);
+    }
+
+    /// Set the floating element.
+    pub fn set_floating(&self, element: Option<Element>) {
+        self.set_floating.set(element);
+    }
+
+    /// Set a position reference, used for positioning only.
+    ///
+    /// Interactions still use the reference element.
+    pub fn set_position_reference(&self, element: Option<Box<dyn VirtualElement<Element>>>) {
+        self.set_position_reference.set(element);
+    }
+}
+
+pub type PropsFn = dyn Fn() -> Vec<Binding>;
+
+pub type ItemPropsFn = dyn Fn(usize) -> Vec<Binding>;
+
+/// Props returned by interaction hooks, to be spread on the reference, floating and item elements.
+#[derive(Clone)]
+pub struct ElementProps {
+    /// Props for the reference element.
+    pub reference: Rc<PropsFn>,
+
+    /// Props for the floating element.
+    pub floating: Rc<PropsFn>,
+
+    /// Props for the item element at the given index, e.g. an option of a listbox.
+    pub item: Rc<ItemPropsFn>,
+}
+
+impl Default for ElementProps {
+    fn default() -> Self {
+        Self {
+            reference: Rc::new(Vec::new),
+            floating: Rc::new(Vec::new),
+            item: Rc::new(|_| Vec::new()),
+        }
+    }
 }
@@ -0,0 +1,259 @@
+use std::rc::Rc;
+
+use leptos::{
+    create_effect, create_signal,
+    ev::{MouseEvent, PointerEvent},
+    html::Binding,
+    EventHandlerFn, MaybeProp, SignalGet, SignalSet,
+};
+
+use floating_ui_dom::{ClientRect, DefaultVirtualElement, Rect, VirtualElement};
+
+use crate::types::{ElementProps, FloatingContext};
+
+/// Axis of the reference element along which the floating element follows the pointer, used by [`use_client_point`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum ClientPointAxis {
+    X,
+    Y,
+    #[default]
+    Both,
+}
+
+impl ClientPointAxis {
+    fn is_x(&self) -> bool {
+        matches!(self, ClientPointAxis::X | ClientPointAxis::Both)
+    }
+
+    fn is_y(&self) -> bool {
+        matches!(self, ClientPointAxis::Y | ClientPointAxis::Both)
+    }
+}
+
+/// Options for [`use_client_point`].
+#[derive(Clone, Default)]
+pub struct UseClientPointOptions {
+    /// Whether the hook is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// Whether the position reference is only updated while the floating element is open.
+    ///
+    /// Defaults to `false`.
+    pub enabled_only_while_open: MaybeProp<bool>,
+
+    /// The axis along which the floating element follows the pointer.
+    ///
+    /// Defaults to [`ClientPointAxis::Both`].
+    pub axis: MaybeProp<ClientPointAxis>,
+
+    /// Initial x-coord, used until the pointer moves over the reference element.
+    ///
+    /// Defaults to [`Option::None`].
+    pub x: MaybeProp<f64>,
+
+    /// Initial y-coord, used until the pointer moves over the reference element.
+    ///
+    /// Defaults to [`Option::None`].
+    pub y: MaybeProp<f64>,
+}
+
+impl UseClientPointOptions {
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled = value;
+        self
+    }
+
+    /// Set `enabled_only_while_open` option.
+    pub fn enabled_only_while_open(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled_only_while_open = value;
+        self
+    }
+
+    /// Set `axis` option.
+    pub fn axis(mut self, value: MaybeProp<ClientPointAxis>) -> Self {
+        self.axis = value;
+        self
+    }
+
+    /// Set `x` option.
+    pub fn x(mut self, value: MaybeProp<f64>) -> Self {
+        self.x = value;
+        self
+    }
+
+    /// Set `y` option.
+    pub fn y(mut self, value: MaybeProp<f64>) -> Self {
+        self.y = value;
+        self
+    }
+}
+
+/// The rect of the client point on the reference element, collapsed on the followed axes.
+pub fn get_client_point_rect(
+    reference_rect: Rect,
+    axis: ClientPointAxis,
+    x: Option<f64>,
+    y: Option<f64>,
+) -> ClientRect {
+    let (x, width) = match (axis.is_x(), x) {
+        (true, Some(x)) => (x, 0.0),
+        _ => (reference_rect.x, reference_rect.width),
+    };
+    let (y, height) = match (axis.is_y(), y) {
+        (true, Some(y)) => (y, 0.0),
+        _ => (reference_rect.y, reference_rect.height),
+    };
+
+    Rect {
+        x,
+        y,
+        width,
+        height,
+    }
+    .into()
+}
+
+/// Positions the floating element relative to a client point (in the viewport), such as the mouse position.
+/// The position reference of [`use_floating`][crate::use_floating::use_floating] is set to a virtual element at this point.
+///
+/// See <https://floating-ui.com/docs/useClientPoint> for the original documentation.
+pub fn use_client_point(context: FloatingContext, options: UseClientPointOptions) -> ElementProps {
+    let FloatingContext {
+        open,
+        reference,
+        refs,
+        ..
+    } = context;
+    let UseClientPointOptions {
+        enabled,
+        enabled_only_while_open,
+        axis,
+        x,
+        y,
+    } = options;
+
+    let enabled = move || enabled.get().unwrap_or(true);
+    let enabled_only_while_open = move || enabled_only_while_open.get().unwrap_or(false);
+    let axis = move || axis.get().unwrap_or_default();
+
+    let (client_point, set_client_point) = create_signal::<Option<(f64, f64)>>(None);
+
+    create_effect(move |was_active: Option<bool>| {
+        let active = enabled() && (open.get() || !enabled_only_while_open());
+
+        if !active {
+            if was_active == Some(true) && !enabled() {
+                refs.set_position_reference(None);
+            }
+            return false;
+        }
+
+        let (x, y) = match client_point.get() {
+            Some((x, y)) => (Some(x), Some(y)),
+            None => (x.get(), y.get()),
+        };
+
+        match (reference.get(), x.is_some() || y.is_some()) {
+            (Some(reference), true) => {
+                let axis = axis();
+                let context_element = reference.clone();
+                let virtual_element: Box<dyn VirtualElement<web_sys::Element>> = Box::new(
+                    DefaultVirtualElement::new(Box::new(move || {
+                        let rect = reference.get_bounding_client_rect();
+
+                        get_client_point_rect(
+                            Rect {
+                                x: rect.x(),
+                                y: rect.y(),
+                                width: rect.width(),
+                                height: rect.height(),
+                            },
+                            axis,
+                            x,
+                            y,
+                        )
+                    }))
+                    .context_element(context_element),
+                );
+
+                refs.set_position_reference(Some(virtual_element));
+                true
+            }
+            _ => was_active.unwrap_or(false),
+        }
+    });
+
+    let set_from_event = move |event: &MouseEvent| {
+        if enabled() {
+            set_client_point.set(Some((event.client_x() as f64, event.client_y() as f64)));
+        }
+    };
+
+    let reference = move || -> Vec<Binding> {
+        vec![
+            EventHandlerFn::Pointerenter(Box::new(move |event: PointerEvent| {
+                set_from_event(&event);
+            }))
+            .into(),
+            EventHandlerFn::Pointerdown(Box::new(move |event: PointerEvent| {
+                set_from_event(&event);
+            }))
+            .into(),
+            EventHandlerFn::Mousemove(Box::new(move |event: MouseEvent| {
+                set_from_event(&event);
+            }))
+            .into(),
+        ]
+    };
+
+    ElementProps {
+        reference: Rc::new(reference),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_client_point_rect() {
+        let reference_rect = Rect {
+            x: 10.0,
+            y: 20.0,
+            width: 100.0,
+            height: 50.0,
+        };
+
+        let rect = get_client_point_rect(
+            reference_rect.clone(),
+            ClientPointAxis::Both,
+            Some(30.0),
+            Some(40.0),
+        );
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (30.0, 40.0, 0.0, 0.0)
+        );
+
+        let rect = get_client_point_rect(
+            reference_rect.clone(),
+            ClientPointAxis::X,
+            Some(30.0),
+            Some(40.0),
+        );
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (30.0, 20.0, 0.0, 50.0)
+        );
+
+        let rect = get_client_point_rect(reference_rect, ClientPointAxis::Y, None, Some(40.0));
+        assert_eq!(
+            (rect.x, rect.y, rect.width, rect.height),
+            (10.0, 40.0, 100.0, 0.0)
+        );
+    }
+}
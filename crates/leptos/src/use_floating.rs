@@ -3,17 +3,22 @@ use std::{cell::RefCell, marker::PhantomData, rc::Rc};
 use leptos::{
     create_effect, create_memo, create_signal,
     html::{AnyElement, ElementDescriptor},
-    on_cleanup, watch, MaybeProp, NodeRef, SignalGet, SignalGetUntracked, SignalSet,
+    on_cleanup, watch, MaybeProp, NodeRef, Signal, SignalGet, SignalGetUntracked, SignalSet,
+    SignalWith,
 };
+use web_sys::Element;
 
 use floating_ui_dom::{
-    compute_position, ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement,
-    Strategy, VirtualElement,
+    compute_position, ClientRect, ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual,
+    Placement, Strategy, TransformOriginData, VirtualElement, TRANSFORM_ORIGIN_NAME,
 };
 
 use crate::{
     node_ref::NodeRefAsElement,
-    types::{FloatingStyles, UseFloatingOptions, UseFloatingReturn, WhileElementsMountedCleanupFn},
+    types::{
+        FloatingContext, FloatingStyles, UseFloatingOptions, UseFloatingRefs, UseFloatingReturn,
+        WhileElementsMountedCleanupFn,
+    },
     utils::{get_dpr::get_dpr, round_by_dpr::round_by_dpr},
 };
 
@@ -109,7 +114,33 @@ where
     }
 }
 
+/// Virtual position reference which uses the DOM reference element as context element,
+/// so [`auto_update`][floating_ui_dom::auto_update] observes the overflow ancestors of the DOM reference.
+#[derive(Clone)]
+struct PositionReference {
+    virtual_element: Box<dyn VirtualElement<Element>>,
+    dom_reference: Option<Element>,
+}
+
+impl VirtualElement<Element> for PositionReference {
+    fn get_bounding_client_rect(&self) -> ClientRect {
+        self.virtual_element.get_bounding_client_rect()
+    }
+
+    fn get_client_rects(&self) -> Option<Vec<ClientRect>> {
+        self.virtual_element.get_client_rects()
+    }
+
+    fn context_element(&self) -> Option<Element> {
+        self.dom_reference
+            .clone()
+            .or_else(|| self.virtual_element.context_element())
+    }
+}
+
 /// Computes the `x` and `y` coordinates that will place the floating element next to a reference element.
+///
+/// The elements can also be set dynamically with the returned [`UseFloatingRefs`], which take precedence over `reference` and `floating`.
 pub fn use_floating<
     Reference: NodeRefAsElement<ReferenceEl> + Copy + 'static,
     ReferenceEl: ElementDescriptor + Clone + 'static,
@@ -138,6 +169,9 @@ pub fn use_floating<
     let transform_option = move || options.transform.get().unwrap_or(true);
     let options_while_elements_mounted = options.while_elements_mounted.clone();
     let while_elements_mounted_untracked = move || options_while_elements_mounted.get_untracked();
+    let options_on_open_change = options.on_open_change.clone();
+    let options_position_reference = options.position_reference.clone();
+    let options_position_reference = Signal::derive(move || options_position_reference.get());
 
     let (x, set_x) = create_signal(0.0);
     let (y, set_y) = create_signal(0.0);
@@ -145,6 +179,61 @@ pub fn use_floating<
     let (placement, set_placement) = create_signal(placement_option_untracked());
     let (middleware_data, set_middleware_data) = create_signal(MiddlewareData::default());
     let (is_positioned, set_is_positioned) = create_signal(false);
+
+    let (reference_element, set_reference_element) = create_signal::<Option<Element>>(None);
+    let (floating_element, set_floating_element) = create_signal::<Option<Element>>(None);
+    let (position_reference, set_position_reference) =
+        create_signal::<Option<Box<dyn VirtualElement<Element>>>>(None);
+    let refs = UseFloatingRefs {
+        set_reference: set_reference_element,
+        set_floating: set_floating_element,
+        set_position_reference,
+    };
+
+    let reference = Signal::derive(move || reference.get());
+    // The reference element used for interactions.
+    let dom_reference = move || {
+        reference_element
+            .get()
+            .map(OwnedElementOrVirtual::from)
+            .or_else(|| reference.get().and_then(|reference| reference.get()))
+    };
+    let dom_reference_untracked = move || {
+        reference_element
+            .get_untracked()
+            .map(OwnedElementOrVirtual::from)
+            .or_else(|| {
+                reference
+                    .get_untracked()
+                    .and_then(|reference| reference.get_untracked())
+            })
+    };
+    // The reference element used for positioning, which can differ from the DOM reference element.
+    let position_reference_untracked = move || {
+        let dom_reference = dom_reference_untracked();
+
+        match position_reference
+            .get_untracked()
+            .or_else(|| options_position_reference.get_untracked())
+        {
+            Some(virtual_element) => {
+                let position_reference: Box<dyn VirtualElement<Element>> =
+                    Box::new(PositionReference {
+                        virtual_element,
+                        dom_reference: dom_reference.and_then(|reference| reference.resolve()),
+                    });
+                Some(OwnedElementOrVirtual::from(position_reference))
+            }
+            None => dom_reference,
+        }
+    };
+    let floating_resolved = move || floating_element.get().or_else(|| floating.get_as_element());
+    let floating_resolved_untracked = move || {
+        floating_element
+            .get_untracked()
+            .or_else(|| floating.get_untracked_as_element())
+    };
+
     let floating_styles = create_memo(move |_| {
         let initial_styles = FloatingStyles {
             position: strategy.get(),
@@ -152,9 +241,24 @@ pub fn use_floating<
             left: "0".into(),
             transform: None,
             will_change: None,
+            visibility: None,
+            transform_origin: middleware_data
+                .with(|middleware_data| {
+                    middleware_data.get_as::<TransformOriginData>(TRANSFORM_ORIGIN_NAME)
+                })
+                .map(|data| format!("{}px {}px", data.x, data.y)),
         };
 
-        if let Some(floating_element) = floating.get_as_element() {
+        // The server cannot position the floating element, so it is hidden until it is positioned on the client.
+        // This keeps the styles rendered on the server and during hydration identical.
+        if cfg!(any(feature = "ssr", feature = "hydrate")) && !is_positioned.get() {
+            return FloatingStyles {
+                visibility: Some("hidden".into()),
+                ..initial_styles
+            };
+        }
+
+        if let Some(floating_element) = floating_resolved() {
             let x_val = round_by_dpr(&floating_element, x.get());
             let y_val = round_by_dpr(&floating_element, y.get());
 
@@ -179,28 +283,32 @@ pub fn use_floating<
         }
     });
 
-    let update_reference = reference.clone();
     let update = move || {
-        if let Some(reference) = update_reference.get_untracked() {
-            if let (Some(reference_element), Some(floating_element)) = (
-                reference.get_untracked(),
-                floating.get_untracked_as_element(),
-            ) {
-                let config = ComputePositionConfig {
-                    placement: Some(placement_option_untracked()),
-                    strategy: Some(strategy_option_untracked()),
-                    middleware: middleware_option_untracked(),
-                };
-
-                let position =
-                    compute_position((&reference_element).into(), &floating_element, Some(config));
-                set_x.set(position.x);
-                set_y.set(position.y);
-                set_strategy.set(position.strategy);
-                set_placement.set(position.placement);
-                set_middleware_data.set(position.middleware_data);
-                set_is_positioned.set(true);
-            }
+        if cfg!(feature = "ssr") {
+            return;
+        }
+
+        if let (Some(reference_element), Some(floating_element)) = (
+            position_reference_untracked(),
+            floating_resolved_untracked(),
+        ) {
+            let config = ComputePositionConfig {
+                placement: Some(placement_option_untracked()),
+                strategy: Some(strategy_option_untracked()),
+                middleware: middleware_option_untracked(),
+                previous_placement: is_positioned
+                    .get_untracked()
+                    .then(|| placement.get_untracked()),
+            };
+
+            let position =
+                compute_position((&reference_element).into(), &floating_element, Some(config));
+            set_x.set(position.x);
+            set_y.set(position.y);
+            set_strategy.set(position.strategy);
+            set_placement.set(position.placement);
+            set_middleware_data.set(position.middleware_data);
+            set_is_positioned.set(true);
         }
     };
     let update_rc = Rc::new(update);
@@ -217,20 +325,16 @@ pub fn use_floating<
     };
     let cleanup_rc = Rc::new(cleanup);
 
-    let attach_reference = reference.clone();
     let attach_update_rc = update_rc.clone();
     let attach_cleanup_rc = cleanup_rc.clone();
     let attach_while_elements_mounted_cleanup = while_elements_mounted_cleanup.clone();
     let attach = move || {
         attach_cleanup_rc();
 
-        if let (Some(while_elements_mounted), Some(reference)) = (
-            while_elements_mounted_untracked(),
-            attach_reference.get_untracked(),
-        ) {
+        if let Some(while_elements_mounted) = while_elements_mounted_untracked() {
             if let (Some(reference_element), Some(floating_element)) = (
-                reference.get_untracked(),
-                floating.get_untracked_as_element(),
+                position_reference_untracked(),
+                floating_resolved_untracked(),
             ) {
                 attach_while_elements_mounted_cleanup.replace(Some(while_elements_mounted(
                     (&reference_element).into(),
@@ -279,6 +383,18 @@ pub fn use_floating<
         }
     });
 
+    // Elements set through refs are already mounted, so they are attached as soon as they change.
+    let refs_attach = attach_rc.clone();
+    create_effect(move |previous: Option<()>| {
+        reference_element.track();
+        floating_element.track();
+        position_reference.track();
+
+        if previous.is_some() {
+            refs_attach();
+        }
+    });
+
     create_effect(move |_| {
         reset();
     });
@@ -307,6 +423,14 @@ pub fn use_floating<
         },
         false,
     );
+    let position_reference_attach_rc = attach_rc.clone();
+    let _ = watch(
+        move || options.position_reference.get(),
+        move |_, _, _| {
+            position_reference_attach_rc();
+        },
+        false,
+    );
     let _ = watch(
         move || options.while_elements_mounted.get(),
         move |_, _, _| {
@@ -319,6 +443,23 @@ pub fn use_floating<
         cleanup_rc();
     });
 
+    let context = FloatingContext {
+        open: Signal::derive(open_option),
+        on_open_change: Rc::new(move |open| {
+            if let Some(on_open_change) = options_on_open_change.get_untracked() {
+                on_open_change(open);
+            }
+        }),
+        placement: placement.into(),
+        strategy: strategy.into(),
+        middleware_data: middleware_data.into(),
+        reference: Signal::derive(move || {
+            dom_reference().and_then(|reference| reference.resolve())
+        }),
+        floating: Signal::derive(floating_resolved),
+        refs,
+    };
+
     UseFloatingReturn {
         x: x.into(),
         y: y.into(),
@@ -328,14 +469,19 @@ pub fn use_floating<
         is_positioned: is_positioned.into(),
         floating_styles: floating_styles.into(),
         update: update_rc.clone(),
+        refs,
+        context,
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use floating_ui_dom::{ClientRect, DefaultVirtualElement, Rect, Strategy, VirtualElement};
     use leptos::{html::Div, *};
     use wasm_bindgen_test::*;
 
+    use crate::test_utils::tick;
+
     use super::*;
 
     wasm_bindgen_test_configure!(run_in_browser);
@@ -369,4 +515,170 @@ mod tests {
         //     Some("true".into())
         // );
     }
+
+    #[wasm_bindgen_test]
+    async fn prefers_reference_set_through_refs() {
+        #[component]
+        fn Component() -> impl IntoView {
+            let reference = create_node_ref::<Div>();
+            let callback_reference = create_node_ref::<Div>();
+            let floating = create_node_ref::<Div>();
+            let UseFloatingReturn { refs, context, .. } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default(),
+            );
+
+            callback_reference.on_load(move |element| {
+                refs.set_reference(Some((*element).clone().into()));
+            });
+
+            view! {
+                <div _ref=reference id="test-node-reference" />
+                <div _ref=callback_reference id="test-callback-reference" />
+                <div _ref=floating />
+                <div id="test-callback-dom-reference">
+                    {move || context.reference.get().map(|reference| reference.id())}
+                </div>
+            }
+        }
+
+        let document = document();
+        mount_to(document.body().unwrap(), Component);
+
+        tick().await;
+        assert_eq!(
+            document
+                .get_element_by_id("test-callback-dom-reference")
+                .and_then(|element| element.text_content()),
+            Some("test-callback-reference".into())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn positions_to_position_reference() {
+        #[component]
+        fn Component(position_rect: ReadSignal<Option<Rect>>) -> impl IntoView {
+            let reference = create_node_ref::<Div>();
+            let floating = create_node_ref::<Div>();
+            let UseFloatingReturn {
+                x,
+                y,
+                refs,
+                context,
+                ..
+            } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default().strategy(Strategy::Fixed.into()),
+            );
+
+            create_effect(move |_| {
+                refs.set_position_reference(position_rect.get().map(|rect| {
+                    let virtual_element: Box<dyn VirtualElement<Element>> =
+                        Box::new(DefaultVirtualElement::new(Box::new(move || {
+                            ClientRect::from(rect.clone())
+                        })));
+                    virtual_element
+                }));
+            });
+
+            view! {
+                <div
+                    _ref=reference
+                    id="test-reference"
+                    style="position: fixed; top: 0; left: 0; width: 100px; height: 100px;"
+                />
+                <div _ref=floating style="position: fixed; width: 50px; height: 20px;" />
+                <div id="test-position">{move || format!("{}, {}", x.get(), y.get())}</div>
+                <div id="test-dom-reference">
+                    {move || context.reference.get().map(|reference| reference.id())}
+                </div>
+            }
+        }
+
+        let (position_rect, set_position_rect) = create_signal(None);
+
+        let document = document();
+        mount_to(document.body().unwrap(), move || {
+            view! { <Component position_rect=position_rect /> }
+        });
+
+        let text = |id: &str| {
+            document
+                .get_element_by_id(id)
+                .and_then(|element| element.text_content())
+                .expect("Element should exist.")
+        };
+
+        tick().await;
+        assert_eq!(text("test-position"), "25, 100");
+
+        // The position reference is only used for positioning.
+        set_position_rect.set(Some(Rect {
+            x: 200.0,
+            y: 300.0,
+            width: 0.0,
+            height: 0.0,
+        }));
+        tick().await;
+        assert_eq!(text("test-position"), "175, 300");
+        assert_eq!(text("test-dom-reference"), "test-reference");
+
+        set_position_rect.set(None);
+        tick().await;
+        assert_eq!(text("test-position"), "25, 100");
+    }
+
+    #[cfg(any(feature = "ssr", feature = "hydrate"))]
+    #[test]
+    fn hides_floating_element_until_positioned() {
+        let runtime = create_runtime();
+
+        let reference = create_node_ref::<Div>();
+        let floating = create_node_ref::<Div>();
+        let UseFloatingReturn {
+            is_positioned,
+            floating_styles,
+            ..
+        } = use_floating(
+            reference.into_reference(),
+            floating,
+            UseFloatingOptions::default(),
+        );
+
+        assert!(!is_positioned.get_untracked());
+        assert_eq!(
+            String::from(floating_styles.get_untracked()),
+            "position: absolute; top: 0; left: 0; visibility: hidden;"
+        );
+
+        runtime.dispose();
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn renders_hidden_floating_element_on_server() {
+        #[component]
+        fn Component() -> impl IntoView {
+            let reference = create_node_ref::<Div>();
+            let floating = create_node_ref::<Div>();
+            let UseFloatingReturn {
+                floating_styles, ..
+            } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default().while_elements_mounted_auto_update(),
+            );
+
+            view! {
+                <div _ref=reference />
+                <div _ref=floating style=move || floating_styles.get() />
+            }
+        }
+
+        let html = ssr::render_to_string(Component);
+
+        assert!(html.contains("position: absolute; top: 0; left: 0; visibility: hidden;"));
+    }
 }
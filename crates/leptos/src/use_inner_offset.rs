@@ -0,0 +1,332 @@
+use std::rc::Rc;
+
+use leptos::{
+    create_effect,
+    ev::{KeyboardEvent, PointerEvent, WheelEvent},
+    html::Binding,
+    on_cleanup, request_animation_frame, EventHandlerFn, MaybeProp, Signal, SignalGet,
+    SignalGetUntracked, StoredValue,
+};
+use web_sys::{
+    wasm_bindgen::{closure::Closure, JsCast},
+    Element, Event,
+};
+
+use floating_ui_dom::{InnerData, SideLength, INNER_NAME};
+
+use crate::{
+    types::{ElementProps, FloatingContext},
+    utils::is_firefox::is_firefox,
+};
+
+/// Approximate line height used to convert line based wheel deltas to pixels.
+const LINE_HEIGHT: f64 = 16.0;
+
+/// Returns the vertical delta of the wheel event in pixels.
+///
+/// Firefox reports mouse wheel deltas in lines instead of pixels.
+fn get_delta_y(event: &WheelEvent, element: &Element) -> f64 {
+    match event.delta_mode() {
+        WheelEvent::DOM_DELTA_LINE => event.delta_y() * LINE_HEIGHT,
+        WheelEvent::DOM_DELTA_PAGE => event.delta_y() * element.client_height() as f64,
+        _ => event.delta_y(),
+    }
+}
+
+pub type OnInnerOffsetChangeFn = dyn Fn(f64);
+
+/// Options for [`use_inner_offset`].
+#[derive(Clone, Default)]
+pub struct UseInnerOffsetOptions {
+    /// Whether the hook is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// Callback that is called with the change of the inner offset, which should be added to the `offset` option of [`Inner`][`floating_ui_dom::Inner`].
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_change: MaybeProp<Rc<OnInnerOffsetChangeFn>>,
+
+    /// The element that scrolls the list, if it is not the floating element itself.
+    ///
+    /// Defaults to [`Option::None`].
+    pub scroll_element: MaybeProp<Element>,
+}
+
+impl UseInnerOffsetOptions {
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled = value;
+        self
+    }
+
+    /// Set `on_change` option.
+    pub fn on_change(mut self, value: MaybeProp<Rc<OnInnerOffsetChangeFn>>) -> Self {
+        self.on_change = value;
+        self
+    }
+
+    /// Set `scroll_element` option.
+    pub fn scroll_element(mut self, value: MaybeProp<Element>) -> Self {
+        self.scroll_element = value;
+        self
+    }
+}
+
+/// Changes the inner offset of the [`Inner`][`floating_ui_dom::Inner`] middleware when the list is scrolled,
+/// so the floating element grows towards the clipping boundary before the list itself scrolls.
+///
+/// The overflow is read from the [`InnerData`] of the floating context.
+///
+/// See <https://floating-ui.com/docs/inner#useinneroffset> for the original documentation.
+pub fn use_inner_offset(context: FloatingContext, options: UseInnerOffsetOptions) -> ElementProps {
+    let FloatingContext {
+        open,
+        floating,
+        middleware_data,
+        ..
+    } = context;
+    let UseInnerOffsetOptions {
+        enabled,
+        on_change,
+        scroll_element,
+    } = options;
+
+    let enabled = move || enabled.get().unwrap_or(true);
+    let on_change = Signal::derive(move || on_change.get());
+
+    let controlled_scrolling = StoredValue::new(false);
+    let prev_scroll_top: StoredValue<Option<f64>> = StoredValue::new(None);
+
+    let overflow = move || -> Option<SideLength> {
+        middleware_data
+            .get_untracked()
+            .get_as::<InnerData>(INNER_NAME)
+            .map(|data| data.overflow)
+    };
+    let change = move |diff: f64| {
+        if let Some(on_change) = on_change.get_untracked() {
+            on_change(diff);
+        }
+    };
+
+    create_effect(move |_| {
+        let element = scroll_element.get().or_else(|| floating.get());
+
+        if !enabled() || !open.get() {
+            return;
+        }
+        let Some(element) = element else {
+            return;
+        };
+
+        let is_firefox = is_firefox(&element);
+        let wheel_element = element.clone();
+        let on_wheel: Closure<dyn Fn(WheelEvent)> = Closure::new(move |event: WheelEvent| {
+            let Some(overflow) = overflow() else {
+                return;
+            };
+            if event.ctrl_key() {
+                return;
+            }
+
+            let delta_y = get_delta_y(&event, &wheel_element);
+            let is_at_top = overflow.top >= -0.5;
+            let is_at_bottom = overflow.bottom >= -0.5;
+            let scroll_height = wheel_element.scroll_height() as f64;
+            let client_height = wheel_element.client_height() as f64;
+            let remaining_scroll = scroll_height - client_height;
+
+            if scroll_height <= client_height {
+                return;
+            }
+
+            if (!is_at_top && delta_y > 0.0) || (!is_at_bottom && delta_y < 0.0) {
+                event.prevent_default();
+                change(match delta_y < 0.0 {
+                    true => delta_y.max(-remaining_scroll),
+                    false => delta_y.min(remaining_scroll),
+                });
+            } else if is_firefox {
+                // Propagate scrolling during the momentum scrolling phase once it is limited by the boundary.
+                wheel_element.set_scroll_top(wheel_element.scroll_top() + delta_y.round() as i32);
+            }
+        });
+
+        let scroll_element = element.clone();
+        let on_scroll: Closure<dyn Fn(Event)> = Closure::new(move |_: Event| {
+            let Some(overflow) = overflow() else {
+                return;
+            };
+            if !controlled_scrolling.get_value() {
+                return;
+            }
+
+            if let Some(prev_scroll_top) = prev_scroll_top.get_value() {
+                let scroll_diff = scroll_element.scroll_top() as f64 - prev_scroll_top;
+
+                if (overflow.bottom < -0.5 && scroll_diff < -1.0)
+                    || (overflow.top < -0.5 && scroll_diff > 1.0)
+                {
+                    change(scroll_diff);
+                }
+            }
+
+            // Wait for the height change to sync to avoid unexpected scroll jumping.
+            let scroll_element = scroll_element.clone();
+            request_animation_frame(move || {
+                prev_scroll_top.set_value(Some(scroll_element.scroll_top() as f64));
+            });
+        });
+
+        element
+            .add_event_listener_with_callback("wheel", on_wheel.as_ref().unchecked_ref())
+            .expect("Wheel event listener should be added.");
+        element
+            .add_event_listener_with_callback("scroll", on_scroll.as_ref().unchecked_ref())
+            .expect("Scroll event listener should be added.");
+
+        let frame_element = element.clone();
+        request_animation_frame(move || {
+            prev_scroll_top.set_value(Some(frame_element.scroll_top() as f64));
+        });
+
+        on_cleanup(move || {
+            prev_scroll_top.set_value(None);
+
+            element
+                .remove_event_listener_with_callback("wheel", on_wheel.as_ref().unchecked_ref())
+                .expect("Wheel event listener should be removed.");
+            element
+                .remove_event_listener_with_callback("scroll", on_scroll.as_ref().unchecked_ref())
+                .expect("Scroll event listener should be removed.");
+        });
+    });
+
+    let floating = move || -> Vec<Binding> {
+        if !enabled() {
+            return vec![];
+        }
+
+        vec![
+            EventHandlerFn::Keydown(Box::new(move |_: KeyboardEvent| {
+                controlled_scrolling.set_value(true);
+            }))
+            .into(),
+            EventHandlerFn::Wheel(Box::new(move |_: WheelEvent| {
+                controlled_scrolling.set_value(false);
+            }))
+            .into(),
+            EventHandlerFn::Pointermove(Box::new(move |_: PointerEvent| {
+                controlled_scrolling.set_value(false);
+            }))
+            .into(),
+        ]
+    };
+
+    ElementProps {
+        floating: Rc::new(floating),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use floating_ui_dom::MiddlewareData;
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+    use web_sys::WheelEventInit;
+
+    use crate::{
+        test_utils::tick,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    thread_local! {
+        static CHANGES: RefCell<Vec<f64>> = const { RefCell::new(vec![]) };
+    }
+
+    #[component]
+    fn Component() -> impl IntoView {
+        let reference = create_node_ref::<Div>();
+        let floating = create_node_ref::<Div>();
+        let UseFloatingReturn { context, .. } = use_floating(
+            reference.into_reference(),
+            floating,
+            UseFloatingOptions::default(),
+        );
+
+        // The list overflows the clipping boundary on both sides.
+        let mut middleware_data = MiddlewareData::default();
+        middleware_data.set_as(
+            INNER_NAME,
+            InnerData {
+                fallback: false,
+                max_height: 100.0,
+                scroll_top: 0.0,
+                overflow: SideLength {
+                    top: -200.0,
+                    right: 0.0,
+                    bottom: -200.0,
+                    left: 0.0,
+                },
+            },
+        );
+
+        let on_change: Rc<OnInnerOffsetChangeFn> =
+            Rc::new(|diff| CHANGES.with(|changes| changes.borrow_mut().push(diff)));
+
+        use_inner_offset(
+            FloatingContext {
+                middleware_data: Signal::derive(move || middleware_data.clone()),
+                ..context
+            },
+            UseInnerOffsetOptions::default().on_change(on_change.into()),
+        );
+
+        view! {
+            <div _ref=reference />
+            <div _ref=floating id="test-floating" style="height: 100px; overflow: auto;">
+                <div style="height: 500px;" />
+            </div>
+        }
+    }
+
+    fn wheel(delta_y: f64, delta_mode: u32) {
+        let init = WheelEventInit::new();
+        init.set_delta_y(delta_y);
+        init.set_delta_mode(delta_mode);
+        init.set_cancelable(true);
+
+        document()
+            .get_element_by_id("test-floating")
+            .expect("Floating element should exist.")
+            .dispatch_event(
+                &WheelEvent::new_with_event_init_dict("wheel", &init)
+                    .expect("Wheel event should be created."),
+            )
+            .expect("Wheel event should be dispatched.");
+    }
+
+    #[wasm_bindgen_test]
+    async fn changes_offset_when_list_is_scrolled() {
+        mount_to(document().body().unwrap(), Component);
+        tick().await;
+
+        wheel(10.0, WheelEvent::DOM_DELTA_PIXEL);
+        wheel(-3.0, WheelEvent::DOM_DELTA_LINE);
+
+        assert_eq!(
+            CHANGES.with(|changes| changes.borrow().clone()),
+            [10.0, -48.0]
+        );
+    }
+}
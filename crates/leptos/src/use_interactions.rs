@@ -0,0 +1,57 @@
+use std::rc::Rc;
+
+use leptos::html::Binding;
+
+use crate::types::ElementProps;
+
+/// Return of [`use_interactions`].
+#[derive(Clone)]
+pub struct UseInteractionsReturn {
+    props: Vec<ElementProps>,
+}
+
+impl UseInteractionsReturn {
+    /// Merged props for the reference element.
+    pub fn get_reference_props(&self) -> Vec<Binding> {
+        self.props
+            .iter()
+            .flat_map(|props| (props.reference)())
+            .collect()
+    }
+
+    /// Merged props for the floating element.
+    pub fn get_floating_props(&self) -> Vec<Binding> {
+        self.props
+            .iter()
+            .flat_map(|props| (props.floating)())
+            .collect()
+    }
+
+    /// Merged props for the item element at `index`.
+    pub fn get_item_props(&self, index: usize) -> Vec<Binding> {
+        self.props
+            .iter()
+            .flat_map(|props| (props.item)(index))
+            .collect()
+    }
+
+    /// Merged props as a single [`ElementProps`], so they can be merged again.
+    pub fn into_element_props(self) -> ElementProps {
+        let reference = self.clone();
+        let floating = self.clone();
+        let item = self;
+
+        ElementProps {
+            reference: Rc::new(move || reference.get_reference_props()),
+            floating: Rc::new(move || floating.get_floating_props()),
+            item: Rc::new(move |index| item.get_item_props(index)),
+        }
+    }
+}
+
+/// Merges the props returned by interaction hooks, so they can be spread on the elements.
+///
+/// See <https://floating-ui.com/docs/useInteractions> for the original documentation.
+pub fn use_interactions(props: Vec<ElementProps>) -> UseInteractionsReturn {
+    UseInteractionsReturn { props }
+}
@@ -0,0 +1,581 @@
+use std::rc::Rc;
+
+use leptos::{
+    create_effect, create_signal, ev::KeyboardEvent, html::Binding, EventHandlerFn, IntoAttribute,
+    MaybeProp, Signal, SignalGet, SignalGetUntracked, SignalSet, StoredValue, WriteSignal,
+};
+use web_sys::{wasm_bindgen::JsCast, Element, HtmlElement};
+
+use crate::{
+    types::{ElementProps, FloatingContext},
+    utils::composite::{
+        get_max_index, get_min_index, get_navigated_index, is_element_disabled, NavigateArgs,
+        ARROW_DOWN, ARROW_LEFT, ARROW_RIGHT, ARROW_UP,
+    },
+};
+
+pub use crate::utils::composite::ListOrientation;
+
+pub type OnNavigateFn = dyn Fn(Option<usize>);
+
+/// Options for [`use_list_navigation`].
+#[derive(Clone)]
+pub struct UseListNavigationOptions {
+    /// The list of item elements, indexed by item index. Unmounted items are [`Option::None`].
+    pub list_ref: StoredValue<Vec<Option<Element>>>,
+
+    /// Callback that is called when the active index changes.
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_navigate: MaybeProp<Rc<OnNavigateFn>>,
+
+    /// Whether the hook is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// The index of the currently selected item, which is activated when the floating element opens.
+    ///
+    /// Defaults to [`Option::None`].
+    pub selected_index: MaybeProp<usize>,
+
+    /// Whether to activate an item when the floating element opens.
+    ///
+    /// Defaults to `true`.
+    pub focus_item_on_open: MaybeProp<bool>,
+
+    /// Whether hovering an item activates it.
+    ///
+    /// Defaults to `true`.
+    pub focus_item_on_hover: MaybeProp<bool>,
+
+    /// Whether pressing an arrow key on the closed reference element opens the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open_on_arrow_key_down: MaybeProp<bool>,
+
+    /// Whether navigation wraps around when reaching either end of the list.
+    ///
+    /// Defaults to `false`.
+    pub loop_focus: MaybeProp<bool>,
+
+    /// Whether the direction is right-to-left, which swaps the left and right arrow keys.
+    ///
+    /// Defaults to `false`.
+    pub rtl: MaybeProp<bool>,
+
+    /// Whether focus stays on the reference element and the active item is communicated with `aria-activedescendant` instead.
+    ///
+    /// Defaults to `false`.
+    pub virtual_focus: MaybeProp<bool>,
+
+    /// The orientation in which the list is navigated with arrow keys.
+    ///
+    /// Defaults to [`ListOrientation::Vertical`].
+    pub orientation: MaybeProp<ListOrientation>,
+
+    /// The number of columns of a grid. Values larger than `1` enable grid navigation.
+    ///
+    /// Defaults to `1`.
+    pub cols: MaybeProp<usize>,
+
+    /// Indices of items that cannot be activated.
+    ///
+    /// Defaults to the items with a `disabled` or `aria-disabled="true"` attribute.
+    pub disabled_indices: MaybeProp<Vec<usize>>,
+}
+
+impl UseListNavigationOptions {
+    pub fn new(list_ref: StoredValue<Vec<Option<Element>>>) -> Self {
+        UseListNavigationOptions {
+            list_ref,
+            on_navigate: MaybeProp::default(),
+            enabled: MaybeProp::default(),
+            selected_index: MaybeProp::default(),
+            focus_item_on_open: MaybeProp::default(),
+            focus_item_on_hover: MaybeProp::default(),
+            open_on_arrow_key_down: MaybeProp::default(),
+            loop_focus: MaybeProp::default(),
+            rtl: MaybeProp::default(),
+            virtual_focus: MaybeProp::default(),
+            orientation: MaybeProp::default(),
+            cols: MaybeProp::default(),
+            disabled_indices: MaybeProp::default(),
+        }
+    }
+
+    /// Set `list_ref` option.
+    pub fn list_ref(mut self, value: StoredValue<Vec<Option<Element>>>) -> Self {
+        self.list_ref = value;
+        self
+    }
+
+    /// Set `on_navigate` option.
+    pub fn on_navigate(mut self, value: MaybeProp<Rc<OnNavigateFn>>) -> Self {
+        self.on_navigate = value;
+        self
+    }
+
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled = value;
+        self
+    }
+
+    /// Set `selected_index` option.
+    pub fn selected_index(mut self, value: MaybeProp<usize>) -> Self {
+        self.selected_index = value;
+        self
+    }
+
+    /// Set `focus_item_on_open` option.
+    pub fn focus_item_on_open(mut self, value: MaybeProp<bool>) -> Self {
+        self.focus_item_on_open = value;
+        self
+    }
+
+    /// Set `focus_item_on_hover` option.
+    pub fn focus_item_on_hover(mut self, value: MaybeProp<bool>) -> Self {
+        self.focus_item_on_hover = value;
+        self
+    }
+
+    /// Set `open_on_arrow_key_down` option.
+    pub fn open_on_arrow_key_down(mut self, value: MaybeProp<bool>) -> Self {
+        self.open_on_arrow_key_down = value;
+        self
+    }
+
+    /// Set `loop_focus` option.
+    pub fn loop_focus(mut self, value: MaybeProp<bool>) -> Self {
+        self.loop_focus = value;
+        self
+    }
+
+    /// Set `rtl` option.
+    pub fn rtl(mut self, value: MaybeProp<bool>) -> Self {
+        self.rtl = value;
+        self
+    }
+
+    /// Set `virtual_focus` option.
+    pub fn virtual_focus(mut self, value: MaybeProp<bool>) -> Self {
+        self.virtual_focus = value;
+        self
+    }
+
+    /// Set `orientation` option.
+    pub fn orientation(mut self, value: MaybeProp<ListOrientation>) -> Self {
+        self.orientation = value;
+        self
+    }
+
+    /// Set `cols` option.
+    pub fn cols(mut self, value: MaybeProp<usize>) -> Self {
+        self.cols = value;
+        self
+    }
+
+    /// Set `disabled_indices` option.
+    pub fn disabled_indices(mut self, value: MaybeProp<Vec<usize>>) -> Self {
+        self.disabled_indices = value;
+        self
+    }
+}
+
+/// Return of [`use_list_navigation`].
+pub struct UseListNavigationReturn {
+    /// The index of the currently active item.
+    pub active_index: Signal<Option<usize>>,
+
+    /// Sets the index of the currently active item, e.g. from [`use_typeahead`][crate::use_typeahead::use_typeahead].
+    pub set_active_index: WriteSignal<Option<usize>>,
+
+    /// Props for the reference, floating and item elements.
+    pub props: ElementProps,
+}
+
+/// Adds arrow key-based navigation of a list of items, using either real DOM focus or virtual focus.
+///
+/// See <https://floating-ui.com/docs/useListNavigation> for the original documentation.
+pub fn use_list_navigation(
+    context: FloatingContext,
+    options: UseListNavigationOptions,
+) -> UseListNavigationReturn {
+    let FloatingContext {
+        open,
+        on_open_change,
+        floating: floating_element,
+        ..
+    } = context;
+    let UseListNavigationOptions {
+        list_ref,
+        on_navigate,
+        enabled,
+        selected_index,
+        focus_item_on_open,
+        focus_item_on_hover,
+        open_on_arrow_key_down,
+        loop_focus,
+        rtl,
+        virtual_focus,
+        orientation,
+        cols,
+        disabled_indices,
+    } = options;
+
+    let enabled = move || enabled.get_untracked().unwrap_or(true);
+    let virtual_focus = move || virtual_focus.get().unwrap_or(false);
+    let orientation = move || orientation.get_untracked().unwrap_or_default();
+    let focus_item_on_hover = move || focus_item_on_hover.get_untracked().unwrap_or(true);
+
+    let disabled_indices = Signal::derive(move || disabled_indices.get());
+    let is_disabled = move |index: usize| match disabled_indices.get_untracked() {
+        Some(disabled_indices) => disabled_indices.contains(&index),
+        None => list_ref.with_value(|list| {
+            list.get(index)
+                .and_then(|element| element.as_ref())
+                .is_none_or(is_element_disabled)
+        }),
+    };
+
+    let (active_index, set_active_index) = create_signal::<Option<usize>>(None);
+
+    let item_element = move |index: usize| {
+        list_ref.with_value(|list| list.get(index).and_then(|element| element.clone()))
+    };
+
+    // Activate the selected or first item when opening and reset when closing.
+    create_effect(move |previous_open: Option<bool>| {
+        let open = open.get();
+
+        if enabled() && previous_open != Some(open) {
+            if open {
+                if focus_item_on_open.get_untracked().unwrap_or(true) {
+                    let length = list_ref.with_value(|list| list.len());
+                    set_active_index.set(
+                        selected_index
+                            .get_untracked()
+                            .filter(|index| !is_disabled(*index))
+                            .or_else(|| get_min_index(length, &is_disabled)),
+                    );
+                }
+            } else if active_index.get_untracked().is_some() {
+                set_active_index.set(None);
+            }
+        }
+
+        open
+    });
+
+    // Sync the active index to the `on_navigate` callback and move real DOM focus.
+    create_effect(move |_| {
+        let index = active_index.get();
+
+        if let Some(on_navigate) = on_navigate.get_untracked() {
+            on_navigate(index);
+        }
+
+        if open.get_untracked() && !virtual_focus() {
+            if let Some(element) = index.and_then(item_element) {
+                if let Some(element) = element.dyn_ref::<HtmlElement>() {
+                    let _ = element.focus();
+                }
+            }
+        }
+    });
+
+    let navigate = move |event: &KeyboardEvent| {
+        let key = event.key();
+        let length = list_ref.with_value(|list| list.len());
+
+        if let Some(index) = get_navigated_index(NavigateArgs {
+            key: &key,
+            current: active_index.get_untracked(),
+            length,
+            is_disabled: &is_disabled,
+            orientation: orientation(),
+            cols: cols.get_untracked().unwrap_or(1),
+            loop_focus: loop_focus.get_untracked().unwrap_or(false),
+            rtl: rtl.get_untracked().unwrap_or(false),
+        }) {
+            event.prevent_default();
+            event.stop_propagation();
+            set_active_index.set(index);
+        }
+    };
+
+    let active_descendant = move || {
+        match virtual_focus() {
+            true => active_index.get().and_then(item_element),
+            false => None,
+        }
+        .map(|element| element.id())
+        .filter(|id| !id.is_empty())
+    };
+
+    let reference_on_open_change = on_open_change.clone();
+    let reference = move || -> Vec<Binding> {
+        let on_open_change = reference_on_open_change.clone();
+
+        vec![
+            ("aria-activedescendant", active_descendant.into_attribute()).into(),
+            EventHandlerFn::Keydown(Box::new(move |event: KeyboardEvent| {
+                if !enabled() {
+                    return;
+                }
+
+                if open.get_untracked() {
+                    if virtual_focus() {
+                        navigate(&event);
+                    }
+                    return;
+                }
+
+                let key = event.key();
+                let is_arrow = match orientation() {
+                    ListOrientation::Vertical => key == ARROW_DOWN || key == ARROW_UP,
+                    ListOrientation::Horizontal => key == ARROW_LEFT || key == ARROW_RIGHT,
+                    ListOrientation::Both => {
+                        key == ARROW_DOWN
+                            || key == ARROW_UP
+                            || key == ARROW_LEFT
+                            || key == ARROW_RIGHT
+                    }
+                };
+
+                if is_arrow && open_on_arrow_key_down.get_untracked().unwrap_or(true) {
+                    event.prevent_default();
+                    on_open_change(true);
+
+                    if key == ARROW_UP || key == ARROW_LEFT {
+                        let length = list_ref.with_value(|list| list.len());
+                        set_active_index.set(get_max_index(length, &is_disabled));
+                    }
+                }
+            }))
+            .into(),
+        ]
+    };
+
+    let floating = move || -> Vec<Binding> {
+        vec![
+            (
+                "aria-orientation",
+                (move || match orientation() {
+                    ListOrientation::Vertical => Some("vertical"),
+                    ListOrientation::Horizontal => Some("horizontal"),
+                    ListOrientation::Both => None,
+                })
+                .into_attribute(),
+            )
+                .into(),
+            EventHandlerFn::Keydown(Box::new(move |event: KeyboardEvent| {
+                if enabled() {
+                    navigate(&event);
+                }
+            }))
+            .into(),
+        ]
+    };
+
+    let item = move |index: usize| -> Vec<Binding> {
+        vec![
+            (
+                "tabindex",
+                (move || match active_index.get() == Some(index) && !virtual_focus() {
+                    true => "0",
+                    false => "-1",
+                })
+                .into_attribute(),
+            )
+                .into(),
+            EventHandlerFn::Mousemove(Box::new(move |_| {
+                if enabled()
+                    && focus_item_on_hover()
+                    && active_index.get_untracked() != Some(index)
+                    && !is_disabled(index)
+                {
+                    set_active_index.set(Some(index));
+                }
+            }))
+            .into(),
+            EventHandlerFn::Pointerleave(Box::new(move |_| {
+                if !enabled() || !focus_item_on_hover() {
+                    return;
+                }
+
+                set_active_index.set(None);
+
+                if !virtual_focus() {
+                    if let Some(floating) = floating_element.get_untracked() {
+                        if let Some(floating) = floating.dyn_ref::<HtmlElement>() {
+                            let _ = floating.focus();
+                        }
+                    }
+                }
+            }))
+            .into(),
+        ]
+    };
+
+    UseListNavigationReturn {
+        active_index: active_index.into(),
+        set_active_index,
+        props: ElementProps {
+            reference: Rc::new(reference),
+            floating: Rc::new(floating),
+            item: Rc::new(item),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+    use web_sys::KeyboardEventInit;
+
+    use crate::{
+        test_utils::tick,
+        types::OnOpenChangeFn,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    const ITEMS: usize = 4;
+
+    #[component]
+    fn Component(
+        open: RwSignal<bool>,
+        list_ref: StoredValue<Vec<Option<Element>>>,
+    ) -> impl IntoView {
+        let reference = create_node_ref::<Div>();
+        let floating = create_node_ref::<Div>();
+        let on_open_change: Rc<OnOpenChangeFn> = Rc::new(move |value| open.set(value));
+        let UseFloatingReturn { context, .. } = use_floating(
+            reference.into_reference(),
+            floating,
+            UseFloatingOptions::default()
+                .open(open.into())
+                .on_open_change(on_open_change.into()),
+        );
+
+        let UseListNavigationReturn {
+            active_index,
+            props,
+            ..
+        } = use_list_navigation(
+            context,
+            UseListNavigationOptions::new(list_ref)
+                .loop_focus(true.into())
+                .virtual_focus(true.into()),
+        );
+
+        (
+            html::div()
+                .node_ref(reference)
+                .id("test-list-reference")
+                .bindings((props.reference)()),
+            html::div()
+                .node_ref(floating)
+                .bindings((props.floating)())
+                .child(
+                    (0..ITEMS)
+                        .map(|index| {
+                            html::div()
+                                .id(format!("test-list-item-{index}"))
+                                // The second item is disabled.
+                                .attr("aria-disabled", (index == 1).then_some("true"))
+                                .bindings((props.item)(index))
+                        })
+                        .collect_view(),
+                ),
+            html::div()
+                .id("test-list-active")
+                .child(move || active_index.get().map(|index| index.to_string())),
+        )
+    }
+
+    fn element(id: &str) -> Element {
+        document()
+            .get_element_by_id(id)
+            .expect("Element should exist.")
+    }
+
+    fn key_down(key: &str) {
+        let init = KeyboardEventInit::new();
+        init.set_key(key);
+        init.set_bubbles(true);
+        init.set_cancelable(true);
+
+        element("test-list-reference")
+            .dispatch_event(
+                &KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init)
+                    .expect("Keyboard event should be created."),
+            )
+            .expect("Event should be dispatched.");
+    }
+
+    fn active() -> (String, Option<String>) {
+        (
+            element("test-list-active")
+                .text_content()
+                .unwrap_or_default(),
+            element("test-list-reference").get_attribute("aria-activedescendant"),
+        )
+    }
+
+    #[wasm_bindgen_test]
+    async fn navigates_list_with_arrow_keys() {
+        let open = create_rw_signal(false);
+        let list_ref = store_value(vec![]);
+
+        mount_to(document().body().unwrap(), move || {
+            view! { <Component open=open list_ref=list_ref /> }
+        });
+        list_ref.set_value(
+            (0..ITEMS)
+                .map(|index| Some(element(&format!("test-list-item-{index}"))))
+                .collect(),
+        );
+        tick().await;
+
+        assert_eq!(active(), ("".into(), None));
+
+        // Opening activates the first item.
+        open.set(true);
+        tick().await;
+        assert_eq!(active(), ("0".into(), Some("test-list-item-0".into())));
+
+        // The disabled item is skipped.
+        key_down(ARROW_DOWN);
+        tick().await;
+        assert_eq!(active(), ("2".into(), Some("test-list-item-2".into())));
+
+        // Navigation wraps around at both ends of the list.
+        key_down(ARROW_DOWN);
+        key_down(ARROW_DOWN);
+        tick().await;
+        assert_eq!(active(), ("0".into(), Some("test-list-item-0".into())));
+
+        key_down(ARROW_UP);
+        tick().await;
+        assert_eq!(active(), ("3".into(), Some("test-list-item-3".into())));
+
+        // Closing resets the active item.
+        open.set(false);
+        tick().await;
+        assert_eq!(active(), ("".into(), None));
+
+        // Pressing the up arrow key on the closed reference element opens it with the last item active.
+        key_down(ARROW_UP);
+        tick().await;
+        assert!(open.get_untracked());
+        assert_eq!(active(), ("3".into(), Some("test-list-item-3".into())));
+    }
+}
@@ -0,0 +1,422 @@
+use std::rc::Rc;
+
+use leptos::{html::Binding, Attribute, IntoAttribute, MaybeProp, SignalGet};
+
+use crate::{
+    types::{ElementProps, FloatingContext},
+    utils::use_id::use_id,
+};
+
+/// ARIA role of the floating element, used by [`use_role`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Role {
+    Tooltip,
+    #[default]
+    Dialog,
+    AlertDialog,
+    Menu,
+    Listbox,
+    Grid,
+    Tree,
+    Select,
+    Combobox,
+    Label,
+}
+
+impl Role {
+    /// The `role` attribute of the floating element.
+    pub fn floating_role(&self) -> Option<&'static str> {
+        match self {
+            Role::Tooltip => Some("tooltip"),
+            Role::Dialog => Some("dialog"),
+            Role::AlertDialog => Some("alertdialog"),
+            Role::Menu => Some("menu"),
+            Role::Listbox | Role::Select | Role::Combobox => Some("listbox"),
+            Role::Grid => Some("grid"),
+            Role::Tree => Some("tree"),
+            Role::Label => None,
+        }
+    }
+
+    /// The `aria-haspopup` attribute of the reference element.
+    pub fn has_popup(&self) -> Option<&'static str> {
+        match self {
+            Role::Tooltip | Role::Label => None,
+            Role::Dialog | Role::AlertDialog => Some("dialog"),
+            Role::Menu => Some("menu"),
+            Role::Listbox | Role::Select | Role::Combobox => Some("listbox"),
+            Role::Grid => Some("grid"),
+            Role::Tree => Some("tree"),
+        }
+    }
+
+    /// The `role` attribute of the item elements.
+    pub fn item_role(&self) -> Option<&'static str> {
+        match self {
+            Role::Menu => Some("menuitem"),
+            Role::Listbox | Role::Select | Role::Combobox => Some("option"),
+            Role::Grid => Some("gridcell"),
+            Role::Tree => Some("treeitem"),
+            Role::Tooltip | Role::Dialog | Role::AlertDialog | Role::Label => None,
+        }
+    }
+}
+
+/// Options for [`use_role`].
+#[derive(Clone, Default)]
+pub struct UseRoleOptions {
+    /// Whether the hook is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// The role of the floating element.
+    ///
+    /// Defaults to [`Role::Dialog`].
+    pub role: MaybeProp<Role>,
+
+    /// The index of the currently active item, e.g. from [`use_list_navigation`][crate::use_list_navigation::use_list_navigation].
+    ///
+    /// Defaults to [`Option::None`].
+    pub active_index: MaybeProp<usize>,
+
+    /// The index of the currently selected item.
+    ///
+    /// Defaults to [`Option::None`].
+    pub selected_index: MaybeProp<usize>,
+}
+
+impl UseRoleOptions {
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled = value;
+        self
+    }
+
+    /// Set `role` option.
+    pub fn role(mut self, value: MaybeProp<Role>) -> Self {
+        self.role = value;
+        self
+    }
+
+    /// Set `active_index` option.
+    pub fn active_index(mut self, value: MaybeProp<usize>) -> Self {
+        self.active_index = value;
+        self
+    }
+
+    /// Set `selected_index` option.
+    pub fn selected_index(mut self, value: MaybeProp<usize>) -> Self {
+        self.selected_index = value;
+        self
+    }
+}
+
+/// The ID of the item element at `index`, generated by [`use_role`] from the ID of the floating element.
+pub fn get_item_id(floating_id: &str, index: usize) -> String {
+    format!("{floating_id}-item-{index}")
+}
+
+/// Adds base screen reader props to the reference and floating elements for a given [`Role`].
+///
+/// See <https://floating-ui.com/docs/useRole> for the original documentation.
+pub fn use_role(context: FloatingContext, options: UseRoleOptions) -> ElementProps {
+    let FloatingContext { open, .. } = context;
+    let UseRoleOptions {
+        enabled,
+        role,
+        active_index,
+        selected_index,
+    } = options;
+
+    let reference_id: Rc<str> = use_id().into();
+    let floating_id: Rc<str> = use_id().into();
+
+    let enabled = move || enabled.get().unwrap_or(true);
+    let role = move || role.get().unwrap_or_default();
+
+    let attribute = |name: &'static str, value: Attribute| -> Binding { (name, value).into() };
+
+    let reference = {
+        let reference_id = reference_id.clone();
+        let floating_id = floating_id.clone();
+
+        move || -> Vec<Binding> {
+            let reference_id = reference_id.clone();
+            let floating_id = floating_id.clone();
+            let described_by_id = floating_id.clone();
+            let labelled_by_id = floating_id.clone();
+
+            vec![
+                attribute(
+                    "id",
+                    (move || {
+                        (enabled() && matches!(role(), Role::Menu | Role::Combobox))
+                            .then(|| reference_id.to_string())
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "role",
+                    (move || {
+                        (enabled() && matches!(role(), Role::Select | Role::Combobox))
+                            .then_some("combobox")
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "aria-autocomplete",
+                    (move || (enabled() && role() == Role::Combobox).then_some("list"))
+                        .into_attribute(),
+                ),
+                attribute(
+                    "aria-haspopup",
+                    (move || {
+                        enabled()
+                            .then(role)
+                            .filter(|role| *role != Role::Select && *role != Role::Combobox)
+                            .and_then(|role| role.has_popup())
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "aria-expanded",
+                    (move || {
+                        (enabled() && role().has_popup().is_some()).then(|| match open.get() {
+                            true => "true",
+                            false => "false",
+                        })
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "aria-controls",
+                    (move || {
+                        (enabled() && open.get() && role().has_popup().is_some())
+                            .then(|| floating_id.to_string())
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "aria-describedby",
+                    (move || {
+                        (enabled() && open.get() && role() == Role::Tooltip)
+                            .then(|| described_by_id.to_string())
+                    })
+                    .into_attribute(),
+                ),
+                attribute(
+                    "aria-labelledby",
+                    (move || {
+                        (enabled() && role() == Role::Label).then(|| labelled_by_id.to_string())
+                    })
+                    .into_attribute(),
+                ),
+            ]
+        }
+    };
+
+    let floating = {
+        let reference_id = reference_id.clone();
+        let floating_id = floating_id.clone();
+
+        move || -> Vec<Binding> {
+            let reference_id = reference_id.clone();
+            let floating_id = floating_id.clone();
+
+            vec![
+                attribute(
+                    "id",
+                    (move || enabled().then(|| floating_id.to_string())).into_attribute(),
+                ),
+                attribute(
+                    "role",
+                    (move || enabled().then(|| role().floating_role()).flatten()).into_attribute(),
+                ),
+                attribute(
+                    "aria-labelledby",
+                    (move || (enabled() && role() == Role::Menu).then(|| reference_id.to_string()))
+                        .into_attribute(),
+                ),
+            ]
+        }
+    };
+
+    let item = move |index: usize| -> Vec<Binding> {
+        let floating_id = floating_id.clone();
+        let is_option = move || matches!(role(), Role::Listbox | Role::Select | Role::Combobox);
+
+        vec![
+            attribute(
+                "id",
+                (move || {
+                    (enabled() && role().item_role().is_some())
+                        .then(|| get_item_id(&floating_id, index))
+                })
+                .into_attribute(),
+            ),
+            attribute(
+                "role",
+                (move || enabled().then(|| role().item_role()).flatten()).into_attribute(),
+            ),
+            attribute(
+                "aria-selected",
+                (move || {
+                    (enabled() && is_option()).then(|| {
+                        let selected = match role() {
+                            Role::Select => {
+                                active_index.get() == Some(index)
+                                    && selected_index.get() == Some(index)
+                            }
+                            _ => selected_index.get().or(active_index.get()) == Some(index),
+                        };
+
+                        match selected {
+                            true => "true",
+                            false => "false",
+                        }
+                    })
+                })
+                .into_attribute(),
+            ),
+        ]
+    };
+
+    ElementProps {
+        reference: Rc::new(reference),
+        floating: Rc::new(floating),
+        item: Rc::new(item),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        test_utils::tick,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn sets_attributes_for_role() {
+        let (role, set_role) = create_signal(Role::Tooltip);
+        let (open, set_open) = create_signal(false);
+
+        #[component]
+        fn Component(role: ReadSignal<Role>, open: ReadSignal<bool>) -> impl IntoView {
+            let reference = create_node_ref::<Div>();
+            let floating = create_node_ref::<Div>();
+            let UseFloatingReturn { context, .. } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default().open(open.into()),
+            );
+
+            let ElementProps {
+                reference: reference_props,
+                floating: floating_props,
+                item: item_props,
+            } = use_role(
+                context,
+                UseRoleOptions::default()
+                    .role(role.into())
+                    .selected_index(1.into()),
+            );
+
+            html::div().id("test-role").child((
+                html::div().node_ref(reference).bindings(reference_props()),
+                html::div()
+                    .node_ref(floating)
+                    .bindings(floating_props())
+                    .child(html::div().bindings(item_props(1))),
+            ))
+        }
+
+        let document = document();
+        mount_to(document.body().unwrap(), move || {
+            view! { <Component role=role open=open /> }
+        });
+
+        let container = document
+            .get_element_by_id("test-role")
+            .expect("Container element should exist.");
+        let reference = container
+            .first_element_child()
+            .expect("Reference element should exist.");
+        let floating = container
+            .last_element_child()
+            .expect("Floating element should exist.");
+        let item = floating
+            .first_element_child()
+            .expect("Item element should exist.");
+
+        set_open.set(true);
+        tick().await;
+
+        let floating_id = floating.id();
+        assert!(!floating_id.is_empty());
+        assert_eq!(floating.get_attribute("role"), Some("tooltip".into()));
+        assert_eq!(
+            reference.get_attribute("aria-describedby"),
+            Some(floating_id.clone())
+        );
+        assert_eq!(reference.get_attribute("aria-haspopup"), None);
+        assert_eq!(reference.get_attribute("aria-expanded"), None);
+        assert_eq!(item.get_attribute("role"), None);
+
+        set_role.set(Role::Menu);
+        tick().await;
+
+        let reference_id = reference.id();
+        assert!(!reference_id.is_empty());
+        assert_eq!(floating.get_attribute("role"), Some("menu".into()));
+        assert_eq!(
+            floating.get_attribute("aria-labelledby"),
+            Some(reference_id)
+        );
+        assert_eq!(
+            reference.get_attribute("aria-haspopup"),
+            Some("menu".into())
+        );
+        assert_eq!(
+            reference.get_attribute("aria-expanded"),
+            Some("true".into())
+        );
+        assert_eq!(
+            reference.get_attribute("aria-controls"),
+            Some(floating_id.clone())
+        );
+        assert_eq!(reference.get_attribute("aria-describedby"), None);
+        assert_eq!(item.get_attribute("role"), Some("menuitem".into()));
+        assert_eq!(item.id(), get_item_id(&floating_id, 1));
+
+        set_role.set(Role::Combobox);
+        tick().await;
+
+        assert_eq!(floating.get_attribute("role"), Some("listbox".into()));
+        assert_eq!(reference.get_attribute("role"), Some("combobox".into()));
+        assert_eq!(
+            reference.get_attribute("aria-autocomplete"),
+            Some("list".into())
+        );
+        assert_eq!(reference.get_attribute("aria-haspopup"), None);
+        assert_eq!(item.get_attribute("role"), Some("option".into()));
+        assert_eq!(item.get_attribute("aria-selected"), Some("true".into()));
+
+        set_open.set(false);
+        tick().await;
+
+        assert_eq!(
+            reference.get_attribute("aria-expanded"),
+            Some("false".into())
+        );
+        assert_eq!(reference.get_attribute("aria-controls"), None);
+    }
+}
@@ -0,0 +1,404 @@
+use std::time::Duration;
+
+use floating_ui_dom::{Alignment, Placement, Side};
+use leptos::{
+    create_effect, create_signal, leptos_dom::helpers::TimeoutHandle, on_cleanup,
+    request_animation_frame, set_timeout_with_handle, MaybeProp, Signal, SignalGet,
+    SignalGetUntracked, SignalSet, StoredValue,
+};
+
+use crate::types::FloatingContext;
+
+/// Mount/unmount phase of a floating element with an enter/exit transition.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum TransitionStatus {
+    /// The floating element is not mounted.
+    #[default]
+    Unmounted,
+    /// The floating element was just mounted and has its initial styles.
+    Initial,
+    /// The floating element is open and transitioning to its open styles.
+    Open,
+    /// The floating element is closing and transitioning to its close styles, before it is unmounted.
+    Close,
+}
+
+/// Durations of the enter and exit transitions in milliseconds.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TransitionDuration {
+    pub open: u64,
+    pub close: u64,
+}
+
+impl Default for TransitionDuration {
+    fn default() -> Self {
+        Self::from(250)
+    }
+}
+
+impl From<u64> for TransitionDuration {
+    fn from(value: u64) -> Self {
+        TransitionDuration {
+            open: value,
+            close: value,
+        }
+    }
+}
+
+/// CSS declarations as property/value pairs, e.g. `("opacity", "0")`.
+pub type TransitionStyles = Vec<(String, String)>;
+
+/// Options for [`use_transition_status`].
+#[derive(Clone, Default)]
+pub struct UseTransitionStatusOptions {
+    /// The durations of the transitions in milliseconds.
+    ///
+    /// Defaults to `250` for both the enter and exit transitions.
+    pub duration: MaybeProp<TransitionDuration>,
+}
+
+impl UseTransitionStatusOptions {
+    /// Set `duration` option.
+    pub fn duration(mut self, value: MaybeProp<TransitionDuration>) -> Self {
+        self.duration = value;
+        self
+    }
+}
+
+/// Return of [`use_transition_status`].
+pub struct UseTransitionStatusReturn {
+    /// Whether the floating element should be mounted. Stays `true` until the exit transition completes.
+    pub is_mounted: Signal<bool>,
+
+    /// The current transition status.
+    pub status: Signal<TransitionStatus>,
+}
+
+/// Provides a status for the enter and exit transitions of the floating element, keeping it mounted until the exit transition completes.
+///
+/// See <https://floating-ui.com/docs/useTransition#usetransitionstatus> for the original documentation.
+pub fn use_transition_status(
+    context: FloatingContext,
+    options: UseTransitionStatusOptions,
+) -> UseTransitionStatusReturn {
+    let FloatingContext { open, .. } = context;
+    let UseTransitionStatusOptions { duration } = options;
+
+    let (is_mounted, set_is_mounted) = create_signal(false);
+    let (status, set_status) = create_signal(TransitionStatus::Unmounted);
+    let timeout_handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+
+    let clear_timeout = move || {
+        if let Some(handle) = timeout_handle.get_value() {
+            handle.clear();
+        }
+        timeout_handle.set_value(None);
+    };
+
+    create_effect(move |_| {
+        let open = open.get();
+        clear_timeout();
+
+        if open {
+            set_is_mounted.set(true);
+            set_status.set(TransitionStatus::Initial);
+
+            // Apply the open styles on the next frame, so the initial styles are rendered first.
+            request_animation_frame(move || {
+                if status.get_untracked() == TransitionStatus::Initial {
+                    set_status.set(TransitionStatus::Open);
+                }
+            });
+        } else if is_mounted.get_untracked() {
+            set_status.set(TransitionStatus::Close);
+
+            let close_duration = duration.get_untracked().unwrap_or_default().close;
+            timeout_handle.set_value(
+                set_timeout_with_handle(
+                    move || {
+                        timeout_handle.set_value(None);
+                        set_is_mounted.set(false);
+                        set_status.set(TransitionStatus::Unmounted);
+                    },
+                    Duration::from_millis(close_duration),
+                )
+                .ok(),
+            );
+        }
+    });
+
+    on_cleanup(clear_timeout);
+
+    UseTransitionStatusReturn {
+        is_mounted: is_mounted.into(),
+        status: status.into(),
+    }
+}
+
+/// Options for [`use_transition_styles`].
+#[derive(Clone, Default)]
+pub struct UseTransitionStylesOptions {
+    /// The durations of the transitions in milliseconds.
+    ///
+    /// Defaults to `250` for both the enter and exit transitions.
+    pub duration: MaybeProp<TransitionDuration>,
+
+    /// The styles of the floating element when it is mounted.
+    ///
+    /// Defaults to `opacity: 0`.
+    pub initial: MaybeProp<TransitionStyles>,
+
+    /// The styles of the floating element when it is open.
+    ///
+    /// Defaults to unsetting the properties of the initial styles.
+    pub open: MaybeProp<TransitionStyles>,
+
+    /// The styles of the floating element when it is closing.
+    ///
+    /// Defaults to the initial styles.
+    pub close: MaybeProp<TransitionStyles>,
+
+    /// The styles of the floating element in every status. These override the placement-aware `transform-origin`.
+    ///
+    /// Defaults to an empty vector.
+    pub common: MaybeProp<TransitionStyles>,
+}
+
+impl UseTransitionStylesOptions {
+    /// Set `duration` option.
+    pub fn duration(mut self, value: MaybeProp<TransitionDuration>) -> Self {
+        self.duration = value;
+        self
+    }
+
+    /// Set `initial` option.
+    pub fn initial(mut self, value: MaybeProp<TransitionStyles>) -> Self {
+        self.initial = value;
+        self
+    }
+
+    /// Set `open` option.
+    pub fn open(mut self, value: MaybeProp<TransitionStyles>) -> Self {
+        self.open = value;
+        self
+    }
+
+    /// Set `close` option.
+    pub fn close(mut self, value: MaybeProp<TransitionStyles>) -> Self {
+        self.close = value;
+        self
+    }
+
+    /// Set `common` option.
+    pub fn common(mut self, value: MaybeProp<TransitionStyles>) -> Self {
+        self.common = value;
+        self
+    }
+}
+
+/// Return of [`use_transition_styles`].
+pub struct UseTransitionStylesReturn {
+    /// Whether the floating element should be mounted. Stays `true` until the exit transition completes.
+    pub is_mounted: Signal<bool>,
+
+    /// CSS styles to apply to the floating element, in addition to the floating styles.
+    pub styles: Signal<String>,
+}
+
+/// The `transform-origin` at which the floating element points to the reference element for a given placement.
+pub fn get_transform_origin(placement: Placement) -> String {
+    let cross = |start: &str, end: &str| match placement.alignment() {
+        Some(Alignment::Start) => start.to_string(),
+        Some(Alignment::End) => end.to_string(),
+        None => "center".into(),
+    };
+
+    match placement.side() {
+        Side::Top => format!("{} bottom", cross("left", "right")),
+        Side::Bottom => format!("{} top", cross("left", "right")),
+        Side::Left => format!("right {}", cross("top", "bottom")),
+        Side::Right => format!("left {}", cross("top", "bottom")),
+    }
+}
+
+fn transition_property(styles: &TransitionStyles) -> String {
+    styles
+        .iter()
+        .map(|(property, _)| property.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Provides styles for the enter and exit transitions of the floating element, keeping it mounted until the exit transition completes.
+///
+/// See <https://floating-ui.com/docs/useTransition#usetransitionstyles> for the original documentation.
+pub fn use_transition_styles(
+    context: FloatingContext,
+    options: UseTransitionStylesOptions,
+) -> UseTransitionStylesReturn {
+    let placement = context.placement;
+    let UseTransitionStylesOptions {
+        duration,
+        initial,
+        open,
+        close,
+        common,
+    } = options;
+
+    let UseTransitionStatusReturn { is_mounted, status } = use_transition_status(
+        context,
+        UseTransitionStatusOptions::default().duration(duration),
+    );
+
+    let styles = Signal::derive(move || {
+        let duration = duration.get().unwrap_or_default();
+        let initial = initial
+            .get()
+            .unwrap_or_else(|| vec![("opacity".into(), "0".into())]);
+
+        let (properties, transition_duration, declarations) = match status.get() {
+            TransitionStatus::Unmounted | TransitionStatus::Initial => {
+                (transition_property(&initial), None, initial)
+            }
+            TransitionStatus::Open => {
+                let open = open.get();
+                (
+                    transition_property(open.as_ref().unwrap_or(&initial)),
+                    Some(duration.open),
+                    open.unwrap_or_default(),
+                )
+            }
+            TransitionStatus::Close => {
+                let close = close.get().unwrap_or(initial);
+                (transition_property(&close), Some(duration.close), close)
+            }
+        };
+
+        let mut styles: TransitionStyles = vec![
+            ("transition-property".into(), properties),
+            (
+                "transform-origin".into(),
+                get_transform_origin(placement.get()),
+            ),
+        ];
+        if let Some(transition_duration) = transition_duration {
+            styles.push((
+                "transition-duration".into(),
+                format!("{transition_duration}ms"),
+            ));
+        }
+        styles.extend(common.get().unwrap_or_default());
+        styles.extend(declarations);
+
+        styles
+            .into_iter()
+            .map(|(property, value)| format!("{property}: {value};"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    });
+
+    UseTransitionStylesReturn { is_mounted, styles }
+}
+
+#[cfg(test)]
+mod tests {
+    use leptos::{html::Div, *};
+    use wasm_bindgen_test::*;
+
+    use crate::{
+        test_utils::sleep,
+        use_floating::{use_floating, IntoReference},
+        UseFloatingOptions, UseFloatingReturn,
+    };
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[test]
+    fn test_get_transform_origin() {
+        assert_eq!(get_transform_origin(Placement::Top), "center bottom");
+        assert_eq!(get_transform_origin(Placement::BottomStart), "left top");
+        assert_eq!(get_transform_origin(Placement::LeftEnd), "right bottom");
+        assert_eq!(get_transform_origin(Placement::Right), "left center");
+    }
+
+    #[wasm_bindgen_test]
+    async fn keeps_floating_element_mounted_until_closed() {
+        #[component]
+        fn Component(open: ReadSignal<bool>) -> impl IntoView {
+            let reference = create_node_ref::<Div>();
+            let floating = create_node_ref::<Div>();
+            let UseFloatingReturn { context, .. } = use_floating(
+                reference.into_reference(),
+                floating,
+                UseFloatingOptions::default().open(open.into()),
+            );
+
+            let UseTransitionStatusReturn { is_mounted, status } = use_transition_status(
+                context.clone(),
+                UseTransitionStatusOptions::default()
+                    .duration(TransitionDuration::from(100).into()),
+            );
+            let UseTransitionStylesReturn { styles, .. } = use_transition_styles(
+                context,
+                UseTransitionStylesOptions::default()
+                    .duration(TransitionDuration::from(100).into()),
+            );
+
+            view! {
+                <div _ref=reference />
+                <div _ref=floating />
+                <div id="test-transition-status">
+                    {move || format!("{} {:?}", is_mounted.get(), status.get())}
+                </div>
+                <div id="test-transition-styles">{styles}</div>
+            }
+        }
+
+        let (open, set_open) = create_signal(false);
+
+        let document = document();
+        mount_to(document.body().unwrap(), move || {
+            view! { <Component open=open /> }
+        });
+
+        let text = |id: &str| {
+            document
+                .get_element_by_id(id)
+                .and_then(|element| element.text_content())
+                .expect("Element should exist.")
+        };
+
+        assert_eq!(text("test-transition-status"), "false Unmounted");
+
+        // The initial styles are rendered before the open styles.
+        set_open.set(true);
+        assert_eq!(text("test-transition-status"), "true Initial");
+        assert_eq!(
+            text("test-transition-styles"),
+            "transition-property: opacity; transform-origin: center top; opacity: 0;"
+        );
+
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(text("test-transition-status"), "true Open");
+        assert_eq!(
+            text("test-transition-styles"),
+            "transition-property: opacity; transform-origin: center top; transition-duration: 100ms;"
+        );
+
+        // The floating element stays mounted until the close transition completes.
+        set_open.set(false);
+        assert_eq!(text("test-transition-status"), "true Close");
+        assert_eq!(
+            text("test-transition-styles"),
+            "transition-property: opacity; transform-origin: center top; transition-duration: 100ms; opacity: 0;"
+        );
+
+        sleep(Duration::from_millis(50)).await;
+        assert_eq!(text("test-transition-status"), "true Close");
+
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(text("test-transition-status"), "false Unmounted");
+    }
+}
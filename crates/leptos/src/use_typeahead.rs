@@ -0,0 +1,291 @@
+use std::{rc::Rc, time::Duration};
+
+use leptos::{
+    ev::KeyboardEvent, html::Binding, leptos_dom::helpers::TimeoutHandle, set_timeout_with_handle,
+    EventHandlerFn, MaybeProp, Signal, SignalGet, SignalGetUntracked, StoredValue,
+};
+
+use crate::types::{ElementProps, FloatingContext};
+
+pub type OnMatchFn = dyn Fn(usize);
+
+pub type OnTypingChangeFn = dyn Fn(bool);
+
+pub type FindMatchFn = dyn Fn(&[Option<String>], &str) -> Option<usize>;
+
+/// Options for [`use_typeahead`].
+#[derive(Clone, Default)]
+pub struct UseTypeaheadOptions {
+    /// The labels of the items, indexed by item index. Items without a label are [`Option::None`].
+    ///
+    /// Defaults to an empty vector.
+    pub list: MaybeProp<Vec<Option<String>>>,
+
+    /// Callback that is called with the index of the matching item.
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_match: MaybeProp<Rc<OnMatchFn>>,
+
+    /// Callback that is called when the user starts or stops typing.
+    ///
+    /// Defaults to [`Option::None`].
+    pub on_typing_change: MaybeProp<Rc<OnTypingChangeFn>>,
+
+    /// Whether the hook is enabled.
+    ///
+    /// Defaults to `true`.
+    pub enabled: MaybeProp<bool>,
+
+    /// The index of the currently active item, from which the search starts.
+    ///
+    /// Defaults to [`Option::None`].
+    pub active_index: MaybeProp<usize>,
+
+    /// The index of the currently selected item, from which the search starts if there is no active item.
+    ///
+    /// Defaults to [`Option::None`].
+    pub selected_index: MaybeProp<usize>,
+
+    /// Custom function to find the index of the matching item.
+    ///
+    /// Defaults to the first label after the active item that starts with the typed string, ignoring case.
+    pub find_match: MaybeProp<Rc<FindMatchFn>>,
+
+    /// Milliseconds after the last key press at which the typed string is reset.
+    ///
+    /// Defaults to `750`.
+    pub reset_ms: MaybeProp<u64>,
+
+    /// Keys that are ignored.
+    ///
+    /// Defaults to an empty vector.
+    pub ignore_keys: MaybeProp<Vec<String>>,
+}
+
+impl UseTypeaheadOptions {
+    /// Set `list` option.
+    pub fn list(mut self, value: MaybeProp<Vec<Option<String>>>) -> Self {
+        self.list = value;
+        self
+    }
+
+    /// Set `on_match` option.
+    pub fn on_match(mut self, value: MaybeProp<Rc<OnMatchFn>>) -> Self {
+        self.on_match = value;
+        self
+    }
+
+    /// Set `on_typing_change` option.
+    pub fn on_typing_change(mut self, value: MaybeProp<Rc<OnTypingChangeFn>>) -> Self {
+        self.on_typing_change = value;
+        self
+    }
+
+    /// Set `enabled` option.
+    pub fn enabled(mut self, value: MaybeProp<bool>) -> Self {
+        self.enabled = value;
+        self
+    }
+
+    /// Set `active_index` option.
+    pub fn active_index(mut self, value: MaybeProp<usize>) -> Self {
+        self.active_index = value;
+        self
+    }
+
+    /// Set `selected_index` option.
+    pub fn selected_index(mut self, value: MaybeProp<usize>) -> Self {
+        self.selected_index = value;
+        self
+    }
+
+    /// Set `find_match` option.
+    pub fn find_match(mut self, value: MaybeProp<Rc<FindMatchFn>>) -> Self {
+        self.find_match = value;
+        self
+    }
+
+    /// Set `reset_ms` option.
+    pub fn reset_ms(mut self, value: MaybeProp<u64>) -> Self {
+        self.reset_ms = value;
+        self
+    }
+
+    /// Set `ignore_keys` option.
+    pub fn ignore_keys(mut self, value: MaybeProp<Vec<String>>) -> Self {
+        self.ignore_keys = value;
+        self
+    }
+}
+
+fn find_typeahead_match(
+    list: &[Option<String>],
+    start: Option<usize>,
+    string: &str,
+) -> Option<usize> {
+    let string = string.to_lowercase();
+    let start = start.map_or(0, |start| start + 1);
+
+    (0..list.len())
+        .map(|offset| (start + offset) % list.len())
+        .find(|index| {
+            list[*index]
+                .as_ref()
+                .is_some_and(|label| label.to_lowercase().starts_with(&string))
+        })
+}
+
+/// Adds typeahead support to a list of items, matching their labels as the user types.
+///
+/// See <https://floating-ui.com/docs/useTypeahead> for the original documentation.
+pub fn use_typeahead(context: FloatingContext, options: UseTypeaheadOptions) -> ElementProps {
+    let FloatingContext { open, .. } = context;
+    let UseTypeaheadOptions {
+        list,
+        on_match,
+        on_typing_change,
+        enabled,
+        active_index,
+        selected_index,
+        find_match,
+        reset_ms,
+        ignore_keys,
+    } = options;
+
+    let list = Signal::derive(move || list.get());
+    let on_match = Signal::derive(move || on_match.get());
+    let on_typing_change = Signal::derive(move || on_typing_change.get());
+    let find_match = Signal::derive(move || find_match.get());
+    let ignore_keys = Signal::derive(move || ignore_keys.get());
+
+    let string = StoredValue::new(String::new());
+    let previous_index: StoredValue<Option<usize>> = StoredValue::new(None);
+    let timeout_handle: StoredValue<Option<TimeoutHandle>> = StoredValue::new(None);
+
+    let set_typing = move |typing: bool| {
+        if let Some(on_typing_change) = on_typing_change.get_untracked() {
+            on_typing_change(typing);
+        }
+    };
+
+    let on_key_down = move |event: KeyboardEvent| {
+        if !enabled.get_untracked().unwrap_or(true) {
+            return;
+        }
+
+        let key = event.key();
+        let is_typing = string.with_value(|string| !string.is_empty());
+
+        if is_typing && key == " " {
+            event.prevent_default();
+            event.stop_propagation();
+        }
+
+        // Only single printable characters without modifiers take part in typeahead.
+        if key.chars().count() != 1
+            || event.ctrl_key()
+            || event.meta_key()
+            || event.alt_key()
+            || (!is_typing && key == " ")
+            || ignore_keys
+                .get_untracked()
+                .is_some_and(|ignore_keys| ignore_keys.contains(&key))
+        {
+            return;
+        }
+
+        if !is_typing {
+            previous_index.set_value(
+                active_index
+                    .get_untracked()
+                    .or_else(|| selected_index.get_untracked()),
+            );
+            set_typing(true);
+        }
+
+        let list = list.get_untracked().unwrap_or_default();
+
+        // Allow cycling through items that start with the same character by typing it repeatedly,
+        // unless a label starts with a repeated character like "llama" or "aaron".
+        let allow_rapid_succession = list.iter().flatten().all(|label| {
+            let mut chars = label.chars().flat_map(char::to_lowercase);
+            chars.next() != chars.next()
+        });
+        if allow_rapid_succession && string.with_value(|string| *string == key) {
+            string.update_value(|string| string.clear());
+            previous_index.set_value(active_index.get_untracked());
+        }
+
+        string.update_value(|string| string.push_str(&key));
+
+        if let Some(handle) = timeout_handle.get_value() {
+            handle.clear();
+        }
+        timeout_handle.set_value(
+            set_timeout_with_handle(
+                move || {
+                    string.update_value(|string| string.clear());
+                    timeout_handle.set_value(None);
+                    set_typing(false);
+                },
+                Duration::from_millis(reset_ms.get_untracked().unwrap_or(750)),
+            )
+            .ok(),
+        );
+
+        let start = previous_index.get_value();
+        let typed = string.get_value();
+
+        let index = match find_match.get_untracked() {
+            Some(find_match) => find_match(&list, &typed),
+            None => find_typeahead_match(&list, start, &typed),
+        };
+
+        if let Some(index) = index {
+            if let Some(on_match) = on_match.get_untracked() {
+                on_match(index);
+            }
+        }
+    };
+
+    let reference = move || -> Vec<Binding> {
+        vec![
+            EventHandlerFn::Keydown(Box::new(move |event: KeyboardEvent| {
+                if open.get_untracked() {
+                    on_key_down(event);
+                }
+            }))
+            .into(),
+        ]
+    };
+
+    let floating =
+        move || -> Vec<Binding> { vec![EventHandlerFn::Keydown(Box::new(on_key_down)).into()] };
+
+    ElementProps {
+        reference: Rc::new(reference),
+        floating: Rc::new(floating),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_typeahead_match() {
+        let list = vec![
+            Some("Apple".into()),
+            Some("Banana".into()),
+            None,
+            Some("Avocado".into()),
+        ];
+
+        assert_eq!(find_typeahead_match(&list, None, "a"), Some(0));
+        assert_eq!(find_typeahead_match(&list, Some(0), "a"), Some(3));
+        assert_eq!(find_typeahead_match(&list, Some(3), "a"), Some(0));
+        assert_eq!(find_typeahead_match(&list, None, "ban"), Some(1));
+        assert_eq!(find_typeahead_match(&list, None, "cherry"), None);
+    }
+}
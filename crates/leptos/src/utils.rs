@@ -1,2 +1,5 @@
+pub mod composite;
 pub mod get_dpr;
+pub mod is_firefox;
 pub mod round_by_dpr;
+pub mod use_id;
@@ -0,0 +1,360 @@
+use web_sys::Element;
+
+/// Orientation of a list navigated with arrow keys.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum ListOrientation {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+pub const ARROW_UP: &str = "ArrowUp";
+pub const ARROW_DOWN: &str = "ArrowDown";
+pub const ARROW_LEFT: &str = "ArrowLeft";
+pub const ARROW_RIGHT: &str = "ArrowRight";
+pub const HOME: &str = "Home";
+pub const END: &str = "End";
+
+pub fn is_element_disabled(element: &Element) -> bool {
+    element.has_attribute("disabled")
+        || element.get_attribute("aria-disabled").as_deref() == Some("true")
+}
+
+/// Finds the first index that is not disabled, starting after `start` and stepping by `amount`.
+/// Without `start`, the search begins just outside the list (before the first or after the last index).
+pub fn find_non_disabled_index(
+    length: usize,
+    start: Option<usize>,
+    decrement: bool,
+    amount: usize,
+    is_disabled: &dyn Fn(usize) -> bool,
+) -> Option<usize> {
+    let length = length as isize;
+    let amount = amount.max(1) as isize;
+    let mut index = match start {
+        Some(start) => start as isize,
+        None => match decrement {
+            true => length,
+            false => -1,
+        },
+    };
+
+    loop {
+        index += match decrement {
+            true => -amount,
+            false => amount,
+        };
+
+        if index < 0 || index >= length {
+            return None;
+        }
+
+        if !is_disabled(index as usize) {
+            return Some(index as usize);
+        }
+    }
+}
+
+pub fn get_min_index(length: usize, is_disabled: &dyn Fn(usize) -> bool) -> Option<usize> {
+    find_non_disabled_index(length, None, false, 1, is_disabled)
+}
+
+pub fn get_max_index(length: usize, is_disabled: &dyn Fn(usize) -> bool) -> Option<usize> {
+    find_non_disabled_index(length, None, true, 1, is_disabled)
+}
+
+/// Arguments for [`get_navigated_index`].
+pub struct NavigateArgs<'a> {
+    pub key: &'a str,
+    pub current: Option<usize>,
+    pub length: usize,
+    pub is_disabled: &'a dyn Fn(usize) -> bool,
+    pub orientation: ListOrientation,
+    pub cols: usize,
+    pub loop_focus: bool,
+    pub rtl: bool,
+}
+
+/// Computes the index to activate after pressing `key`.
+///
+/// Returns [`Option::None`] if the key does not navigate the list.
+pub fn get_navigated_index(args: NavigateArgs) -> Option<Option<usize>> {
+    let NavigateArgs {
+        key,
+        current,
+        length,
+        is_disabled,
+        orientation,
+        cols,
+        loop_focus,
+        rtl,
+    } = args;
+
+    let min_index = get_min_index(length, is_disabled);
+    let max_index = get_max_index(length, is_disabled);
+
+    if key == HOME {
+        return Some(min_index);
+    }
+    if key == END {
+        return Some(max_index);
+    }
+
+    let (next_key, previous_key) = match rtl {
+        true => (ARROW_LEFT, ARROW_RIGHT),
+        false => (ARROW_RIGHT, ARROW_LEFT),
+    };
+
+    if cols > 1 {
+        return get_grid_navigated_index(GridNavigateArgs {
+            key,
+            current,
+            length,
+            is_disabled,
+            cols,
+            loop_focus,
+            next_key,
+            previous_key,
+        });
+    }
+
+    let is_next = match orientation {
+        ListOrientation::Vertical => key == ARROW_DOWN,
+        ListOrientation::Horizontal => key == next_key,
+        ListOrientation::Both => key == ARROW_DOWN || key == next_key,
+    };
+    let is_previous = match orientation {
+        ListOrientation::Vertical => key == ARROW_UP,
+        ListOrientation::Horizontal => key == previous_key,
+        ListOrientation::Both => key == ARROW_UP || key == previous_key,
+    };
+
+    if is_next {
+        Some(match current {
+            None => min_index,
+            Some(current) => find_non_disabled_index(length, Some(current), false, 1, is_disabled)
+                .or(match loop_focus {
+                    true => min_index,
+                    false => Some(current),
+                }),
+        })
+    } else if is_previous {
+        Some(match current {
+            None => max_index,
+            Some(current) => find_non_disabled_index(length, Some(current), true, 1, is_disabled)
+                .or(match loop_focus {
+                    true => max_index,
+                    false => Some(current),
+                }),
+        })
+    } else {
+        None
+    }
+}
+
+struct GridNavigateArgs<'a> {
+    key: &'a str,
+    current: Option<usize>,
+    length: usize,
+    is_disabled: &'a dyn Fn(usize) -> bool,
+    cols: usize,
+    loop_focus: bool,
+    next_key: &'a str,
+    previous_key: &'a str,
+}
+
+fn get_grid_navigated_index(args: GridNavigateArgs) -> Option<Option<usize>> {
+    let GridNavigateArgs {
+        key,
+        current,
+        length,
+        is_disabled,
+        cols,
+        loop_focus,
+        next_key,
+        previous_key,
+    } = args;
+
+    let min_index = get_min_index(length, is_disabled);
+    let max_index = get_max_index(length, is_disabled);
+
+    let Some(current) = current else {
+        return match key {
+            k if k == ARROW_DOWN || k == next_key => Some(min_index),
+            k if k == ARROW_UP || k == previous_key => Some(max_index),
+            _ => None,
+        };
+    };
+
+    if key == ARROW_DOWN || key == ARROW_UP {
+        let decrement = key == ARROW_UP;
+        let next = find_non_disabled_index(length, Some(current), decrement, cols, is_disabled);
+
+        return Some(match (next, loop_focus) {
+            (Some(next), _) => Some(next),
+            (None, true) => {
+                // Wrap to the same column on the opposite edge of the grid.
+                let column = current % cols;
+                let rows = length.div_ceil(cols);
+                let start = match decrement {
+                    true => (rows - 1) * cols + column,
+                    false => column,
+                };
+                match start < length && !is_disabled(start) {
+                    true => Some(start),
+                    false => {
+                        find_non_disabled_index(length, Some(start), decrement, cols, is_disabled)
+                            .or(Some(current))
+                    }
+                }
+            }
+            (None, false) => Some(current),
+        });
+    }
+
+    if key == next_key || key == previous_key {
+        let decrement = key == previous_key;
+        let row_start = current - current % cols;
+        let row_end = (row_start + cols).min(length) - 1;
+        let next = find_non_disabled_index(length, Some(current), decrement, 1, is_disabled)
+            .filter(|next| (row_start..=row_end).contains(next));
+
+        return Some(match (next, loop_focus) {
+            (Some(next), _) => Some(next),
+            (None, true) => {
+                let row_is_disabled =
+                    |index: usize| !(row_start..=row_end).contains(&index) || is_disabled(index);
+                match decrement {
+                    true => get_max_index(row_end + 1, &row_is_disabled),
+                    false => get_min_index(row_end + 1, &row_is_disabled),
+                }
+                .or(Some(current))
+            }
+            (None, false) => Some(current),
+        });
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn navigate(
+        key: &str,
+        current: Option<usize>,
+        disabled: &[usize],
+        cols: usize,
+        loop_focus: bool,
+        rtl: bool,
+    ) -> Option<Option<usize>> {
+        get_navigated_index(NavigateArgs {
+            key,
+            current,
+            length: 6,
+            is_disabled: &|index| disabled.contains(&index),
+            orientation: ListOrientation::Both,
+            cols,
+            loop_focus,
+            rtl,
+        })
+    }
+
+    #[test]
+    fn test_find_non_disabled_index() {
+        let is_disabled = |index: usize| index == 0 || index == 2;
+
+        assert_eq!(get_min_index(4, &is_disabled), Some(1));
+        assert_eq!(get_max_index(4, &is_disabled), Some(3));
+        assert_eq!(
+            find_non_disabled_index(4, Some(1), false, 1, &is_disabled),
+            Some(3)
+        );
+        assert_eq!(
+            find_non_disabled_index(4, Some(3), false, 1, &is_disabled),
+            None
+        );
+    }
+
+    #[test]
+    fn test_list_navigation() {
+        assert_eq!(
+            navigate(ARROW_DOWN, None, &[], 1, false, false),
+            Some(Some(0))
+        );
+        assert_eq!(
+            navigate(ARROW_UP, None, &[], 1, false, false),
+            Some(Some(5))
+        );
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(0), &[1], 1, false, false),
+            Some(Some(2))
+        );
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(5), &[], 1, false, false),
+            Some(Some(5))
+        );
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(5), &[0], 1, true, false),
+            Some(Some(1))
+        );
+        assert_eq!(
+            navigate(HOME, Some(3), &[0], 1, false, false),
+            Some(Some(1))
+        );
+        assert_eq!(navigate(END, Some(3), &[5], 1, false, false), Some(Some(4)));
+        assert_eq!(navigate("a", Some(3), &[], 1, false, false), None);
+    }
+
+    #[test]
+    fn test_list_navigation_rtl() {
+        assert_eq!(
+            navigate(ARROW_LEFT, Some(2), &[], 1, false, true),
+            Some(Some(3))
+        );
+        assert_eq!(
+            navigate(ARROW_RIGHT, Some(2), &[], 1, false, true),
+            Some(Some(1))
+        );
+    }
+
+    #[test]
+    fn test_grid_navigation() {
+        // 0 1 2
+        // 3 4 5
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(1), &[], 3, false, false),
+            Some(Some(4))
+        );
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(4), &[], 3, false, false),
+            Some(Some(4))
+        );
+        assert_eq!(
+            navigate(ARROW_DOWN, Some(4), &[], 3, true, false),
+            Some(Some(1))
+        );
+        assert_eq!(
+            navigate(ARROW_UP, Some(1), &[], 3, true, false),
+            Some(Some(4))
+        );
+        assert_eq!(
+            navigate(ARROW_RIGHT, Some(2), &[], 3, false, false),
+            Some(Some(2))
+        );
+        assert_eq!(
+            navigate(ARROW_RIGHT, Some(2), &[], 3, true, false),
+            Some(Some(0))
+        );
+        assert_eq!(
+            navigate(ARROW_LEFT, Some(3), &[], 3, true, false),
+            Some(Some(5))
+        );
+        assert_eq!(
+            navigate(ARROW_RIGHT, Some(3), &[4], 3, false, false),
+            Some(Some(5))
+        );
+    }
+}
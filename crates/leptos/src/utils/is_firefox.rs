@@ -0,0 +1,10 @@
+use web_sys::Element;
+
+use floating_ui_dom::dom::get_window;
+
+pub fn is_firefox(element: &Element) -> bool {
+    get_window(Some(element))
+        .navigator()
+        .user_agent()
+        .is_ok_and(|user_agent| user_agent.to_lowercase().contains("firefox"))
+}
@@ -0,0 +1,7 @@
+use leptos::leptos_dom::HydrationCtx;
+
+/// Generates an ID that is stable between server-side rendering and hydration.
+pub fn use_id() -> String {
+    let key = HydrationCtx::id().unwrap_or_else(HydrationCtx::peek_always);
+    format!("floating-ui-{key}")
+}
@@ -0,0 +1,202 @@
+use std::rc::Rc;
+
+use sycamore::reactive::{MaybeDyn, ReadSignal};
+use web_sys::Element;
+
+use floating_ui_dom::{
+    auto_update, AutoUpdateOptions, ElementOrVirtual, MiddlewareData, MiddlewareVec, Placement,
+    Strategy,
+};
+
+pub type WhileElementsMountedFn =
+    dyn Fn(ElementOrVirtual, &Element, Rc<dyn Fn()>) -> WhileElementsMountedCleanupFn;
+
+pub type WhileElementsMountedCleanupFn = Box<dyn Fn()>;
+
+/// Options for [`use_floating`][`crate::use_floating::use_floating`].
+#[derive(Clone)]
+pub struct UseFloatingOptions {
+    /// Represents the open/close state of the floating element.
+    ///
+    /// Defaults to `true`.
+    pub open: MaybeDyn<Option<bool>>,
+
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: MaybeDyn<Option<Placement>>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: MaybeDyn<Option<Strategy>>,
+
+    /// Array of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: MaybeDyn<Option<MiddlewareVec>>,
+
+    ///  Whether to use `transform` for positioning instead of `top` and `left` in the `floatingStyles` object.
+    ///
+    /// Defaults to `true`.
+    pub transform: MaybeDyn<Option<bool>>,
+
+    /// Callback to handle mounting/unmounting of the elements.
+    ///
+    /// Defaults to [`Option::None`].
+    pub while_elements_mounted: MaybeDyn<Option<Rc<WhileElementsMountedFn>>>,
+}
+
+impl Default for UseFloatingOptions {
+    fn default() -> Self {
+        Self {
+            open: None.into(),
+            placement: None.into(),
+            strategy: None.into(),
+            middleware: None.into(),
+            transform: None.into(),
+            while_elements_mounted: None.into(),
+        }
+    }
+}
+
+impl UseFloatingOptions {
+    /// Set `open` option.
+    pub fn open(mut self, value: impl Into<MaybeDyn<Option<bool>>>) -> Self {
+        self.open = value.into();
+        self
+    }
+
+    /// Set `placement` option.
+    pub fn placement(mut self, value: impl Into<MaybeDyn<Option<Placement>>>) -> Self {
+        self.placement = value.into();
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: impl Into<MaybeDyn<Option<Strategy>>>) -> Self {
+        self.strategy = value.into();
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: impl Into<MaybeDyn<Option<MiddlewareVec>>>) -> Self {
+        self.middleware = value.into();
+        self
+    }
+
+    /// Set `transform` option.
+    pub fn transform(mut self, value: impl Into<MaybeDyn<Option<bool>>>) -> Self {
+        self.transform = value.into();
+        self
+    }
+
+    /// Set `while_elements_mounted` option.
+    pub fn while_elements_mounted(
+        mut self,
+        value: impl Into<MaybeDyn<Option<Rc<WhileElementsMountedFn>>>>,
+    ) -> Self {
+        self.while_elements_mounted = value.into();
+        self
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with [`AutoUpdateOptions::default`].
+    pub fn while_elements_mounted_auto_update(self) -> Self {
+        self.while_elements_mounted_auto_update_with_options(AutoUpdateOptions::default())
+    }
+
+    /// Set `while_elements_mounted` option to [`auto_update`] with `options`.
+    pub fn while_elements_mounted_auto_update_with_options(
+        self,
+        options: AutoUpdateOptions,
+    ) -> Self {
+        let auto_update_rc: Rc<WhileElementsMountedFn> =
+            Rc::new(move |reference, floating, update| {
+                auto_update(reference, floating, update, options.clone())
+            });
+        self.while_elements_mounted(Some(auto_update_rc))
+    }
+}
+
+/// CSS styles to apply to the floating element to position it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FloatingStyles {
+    pub position: Strategy,
+    pub top: String,
+    pub left: String,
+    pub transform: Option<String>,
+    pub will_change: Option<String>,
+}
+
+impl FloatingStyles {
+    pub fn style_position(&self) -> String {
+        match self.position {
+            Strategy::Absolute => "absolute".into(),
+            Strategy::Fixed => "fixed".into(),
+        }
+    }
+
+    pub fn style_top(&self) -> String {
+        self.top.clone()
+    }
+
+    pub fn style_left(&self) -> String {
+        self.left.clone()
+    }
+
+    pub fn style_transform(&self) -> Option<String> {
+        self.transform.clone()
+    }
+
+    pub fn style_will_change(&self) -> Option<String> {
+        self.will_change.clone()
+    }
+}
+
+impl From<FloatingStyles> for String {
+    fn from(value: FloatingStyles) -> Self {
+        format!(
+            "position: {}; top: {}; left: {};{}{}",
+            match value.position {
+                Strategy::Absolute => "absolute",
+                Strategy::Fixed => "fixed",
+            },
+            value.top,
+            value.left,
+            value
+                .transform
+                .map_or("".into(), |transform| format!(" transform: {};", transform),),
+            value.will_change.map_or("".into(), |will_change| format!(
+                " will-change: {};",
+                will_change
+            ))
+        )
+    }
+}
+
+/// Return of [`use_floating`][crate::use_floating::use_floating].
+pub struct UseFloatingReturn {
+    /// The x-coord of the floating element.
+    pub x: ReadSignal<f64>,
+
+    /// The y-coord of the floating element.
+    pub y: ReadSignal<f64>,
+
+    /// The stateful placement, which can be different from the initial `placement` passed as options.
+    pub placement: ReadSignal<Placement>,
+
+    /// The strategy to use when positioning the floating element.
+    pub strategy: ReadSignal<Strategy>,
+
+    /// Additional data from middleware.
+    pub middleware_data: ReadSignal<MiddlewareData>,
+
+    /// Indicates if the floating element has been positioned.
+    pub is_positioned: ReadSignal<bool>,
+
+    /// CSS styles to apply to the floating element to position it.
+    pub floating_styles: ReadSignal<FloatingStyles>,
+
+    /// The function to update floating position manually.
+    pub update: Rc<dyn Fn()>,
+}
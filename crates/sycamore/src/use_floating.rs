@@ -0,0 +1,291 @@
+use std::{cell::RefCell, rc::Rc};
+
+use sycamore::{
+    reactive::{create_effect, create_memo, create_signal, on_cleanup, untrack},
+    web::{on_mount, wasm_bindgen::JsCast, NodeRef},
+};
+use web_sys::Element;
+
+use floating_ui_dom::{
+    compute_position, ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement,
+    Strategy, VirtualElement,
+};
+
+use crate::{
+    types::{FloatingStyles, UseFloatingOptions, UseFloatingReturn, WhileElementsMountedCleanupFn},
+    utils::{get_dpr::get_dpr, round_by_dpr::round_by_dpr},
+};
+
+#[derive(Clone)]
+pub enum VirtualElementOrNodeRef {
+    VirtualElement(Box<dyn VirtualElement<Element>>),
+    NodeRef(NodeRef),
+}
+
+impl VirtualElementOrNodeRef {
+    pub fn get(&self) -> Option<OwnedElementOrVirtual> {
+        match self {
+            VirtualElementOrNodeRef::VirtualElement(virtual_element) => {
+                Some(virtual_element.clone().into())
+            }
+            VirtualElementOrNodeRef::NodeRef(node_ref) => {
+                get_element(node_ref).map(|element| element.into())
+            }
+        }
+    }
+
+    pub fn get_untracked(&self) -> Option<OwnedElementOrVirtual> {
+        untrack(|| self.get())
+    }
+}
+
+impl From<Box<dyn VirtualElement<Element>>> for VirtualElementOrNodeRef {
+    fn from(value: Box<dyn VirtualElement<Element>>) -> Self {
+        VirtualElementOrNodeRef::VirtualElement(value)
+    }
+}
+
+impl From<NodeRef> for VirtualElementOrNodeRef {
+    fn from(value: NodeRef) -> Self {
+        VirtualElementOrNodeRef::NodeRef(value)
+    }
+}
+
+fn get_element(node_ref: &NodeRef) -> Option<Element> {
+    node_ref
+        .try_get()
+        .and_then(|node| node.dyn_into::<Element>().ok())
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a reference element.
+pub fn use_floating(
+    reference: VirtualElementOrNodeRef,
+    floating: NodeRef,
+    options: UseFloatingOptions,
+) -> UseFloatingReturn {
+    let UseFloatingOptions {
+        open: open_option,
+        placement: placement_option,
+        strategy: strategy_option,
+        middleware: middleware_option,
+        transform: transform_option,
+        while_elements_mounted: while_elements_mounted_option,
+    } = options;
+
+    let placement_option_untracked = {
+        let placement_option = placement_option.clone();
+        move || untrack(|| placement_option.get()).unwrap_or(Placement::Bottom)
+    };
+    let strategy_option_untracked = {
+        let strategy_option = strategy_option.clone();
+        move || untrack(|| strategy_option.get()).unwrap_or(Strategy::Absolute)
+    };
+    let middleware_option_untracked = {
+        let middleware_option = middleware_option.clone();
+        move || untrack(|| middleware_option.get_clone())
+    };
+    let while_elements_mounted_untracked = {
+        let while_elements_mounted_option = while_elements_mounted_option.clone();
+        move || untrack(|| while_elements_mounted_option.get_clone())
+    };
+
+    let x = create_signal(0.0);
+    let y = create_signal(0.0);
+    let strategy = create_signal(strategy_option_untracked());
+    let placement = create_signal(placement_option_untracked());
+    let middleware_data = create_signal(MiddlewareData::default());
+    let is_positioned = create_signal(false);
+    let floating_styles = create_memo(move || {
+        let initial_styles = FloatingStyles {
+            position: strategy.get(),
+            top: "0".into(),
+            left: "0".into(),
+            transform: None,
+            will_change: None,
+        };
+
+        if let Some(floating_element) = get_element(&floating) {
+            let x_val = round_by_dpr(&floating_element, x.get());
+            let y_val = round_by_dpr(&floating_element, y.get());
+
+            if transform_option.get().unwrap_or(true) {
+                FloatingStyles {
+                    transform: Some(format!("translate({x_val}px, {y_val}px)")),
+                    will_change: match get_dpr(&floating_element) >= 1.5 {
+                        true => Some("transform".into()),
+                        false => None,
+                    },
+                    ..initial_styles
+                }
+            } else {
+                FloatingStyles {
+                    left: format!("{x_val}px"),
+                    top: format!("{y_val}px"),
+                    ..initial_styles
+                }
+            }
+        } else {
+            initial_styles
+        }
+    });
+
+    let update_reference = reference.clone();
+    let update = move || {
+        if let (Some(reference_element), Some(floating_element)) = (
+            update_reference.get_untracked(),
+            untrack(|| get_element(&floating)),
+        ) {
+            let config = ComputePositionConfig {
+                placement: Some(placement_option_untracked()),
+                strategy: Some(strategy_option_untracked()),
+                middleware: middleware_option_untracked(),
+                previous_placement: is_positioned
+                    .get_untracked()
+                    .then(|| placement.get_untracked()),
+            };
+
+            let position =
+                compute_position((&reference_element).into(), &floating_element, Some(config));
+            x.set(position.x);
+            y.set(position.y);
+            strategy.set(position.strategy);
+            placement.set(position.placement);
+            middleware_data.set(position.middleware_data);
+            is_positioned.set(true);
+        }
+    };
+    let update_rc: Rc<dyn Fn()> = Rc::new(update);
+
+    let while_elements_mounted_cleanup: Rc<RefCell<Option<WhileElementsMountedCleanupFn>>> =
+        Rc::new(RefCell::new(None));
+
+    let cleanup_while_elements_mounted_cleanup = while_elements_mounted_cleanup.clone();
+    let cleanup = move || {
+        if let Some(while_elements_mounted_cleanup) = cleanup_while_elements_mounted_cleanup.take()
+        {
+            while_elements_mounted_cleanup();
+        }
+    };
+    let cleanup_rc = Rc::new(cleanup);
+
+    let attach_reference = reference.clone();
+    let attach_update_rc = update_rc.clone();
+    let attach_cleanup_rc = cleanup_rc.clone();
+    let attach = move || {
+        attach_cleanup_rc();
+
+        if let Some(while_elements_mounted) = while_elements_mounted_untracked() {
+            if let (Some(reference_element), Some(floating_element)) = (
+                attach_reference.get_untracked(),
+                untrack(|| get_element(&floating)),
+            ) {
+                while_elements_mounted_cleanup.replace(Some(while_elements_mounted(
+                    (&reference_element).into(),
+                    &floating_element,
+                    attach_update_rc.clone(),
+                )));
+            }
+        } else {
+            attach_update_rc();
+        }
+    };
+
+    // Node refs are set before the elements are inserted into the document, so wait until mounted.
+    let is_mounted = create_signal(false);
+    on_mount(move || is_mounted.set(true));
+
+    create_effect(move || {
+        if !is_mounted.get() {
+            return;
+        }
+
+        reference.get();
+        floating.try_get();
+        while_elements_mounted_option.track();
+
+        attach();
+    });
+
+    let options_update_rc = update_rc.clone();
+    create_effect(move || {
+        placement_option.track();
+        strategy_option.track();
+        middleware_option.track();
+
+        if untrack(|| is_mounted.get()) {
+            options_update_rc();
+        }
+    });
+
+    create_effect(move || {
+        if !open_option.get().unwrap_or(true) {
+            is_positioned.set(false);
+        }
+    });
+
+    on_cleanup(move || {
+        cleanup_rc();
+    });
+
+    UseFloatingReturn {
+        x: *x,
+        y: *y,
+        placement: *placement,
+        strategy: *strategy,
+        middleware_data: *middleware_data,
+        is_positioned: *is_positioned,
+        floating_styles,
+        update: update_rc,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sycamore::prelude::*;
+    use wasm_bindgen_futures::{js_sys::Promise, JsFuture};
+    use wasm_bindgen_test::*;
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Waits for the next task, so the `on_mount` callbacks have run.
+    async fn tick() {
+        let promise = Promise::new(&mut |resolve, _| {
+            window()
+                .set_timeout_with_callback(&resolve)
+                .expect("Timeout should be set.");
+        });
+
+        JsFuture::from(promise)
+            .await
+            .expect("Timeout should be resolved.");
+    }
+
+    #[wasm_bindgen_test]
+    async fn updates_is_positioned_when_position_is_computed() {
+        #[component]
+        fn Component() -> View {
+            let reference = create_node_ref();
+            let floating = create_node_ref();
+            let UseFloatingReturn { is_positioned, .. } =
+                use_floating(reference.into(), floating, UseFloatingOptions::default());
+
+            view! {
+                div(r#ref=reference)
+                div(r#ref=floating)
+                div(id="test-is-positioned") { (move || is_positioned.get().to_string()) }
+            }
+        }
+
+        sycamore::render(Component);
+        tick().await;
+
+        assert_eq!(
+            document()
+                .get_element_by_id("test-is-positioned")
+                .and_then(|element| element.text_content()),
+            Some("true".into())
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use std::{cell::Cell, rc::Rc};
+
+use ratatui::layout::Rect;
+
+/// Area of a widget in the terminal cell grid.
+///
+/// The area is shared between clones, so middleware like [`Size`][`crate::Size`] can resize the floating element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Element {
+    area: Rc<Cell<Rect>>,
+}
+
+impl Element {
+    pub fn new(area: Rect) -> Self {
+        Element {
+            area: Rc::new(Cell::new(area)),
+        }
+    }
+
+    pub fn area(&self) -> Rect {
+        self.area.get()
+    }
+
+    pub fn set_area(&self, area: Rect) {
+        self.area.set(area);
+    }
+
+    pub fn set_size(&self, width: u16, height: u16) {
+        self.area.set(Rect {
+            width,
+            height,
+            ..self.area.get()
+        });
+    }
+}
+
+impl From<Rect> for Element {
+    fn from(value: Rect) -> Self {
+        Element::new(value)
+    }
+}
+
+/// Window of the terminal platform.
+///
+/// Terminals have no offset parents, so this is never constructed by the platform.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Terminal;
@@ -0,0 +1,104 @@
+//! Rust port of [Floating UI](https://floating-ui.com/).
+//!
+//! This is the library to use Floating UI in terminal user interfaces, wrapping [`floating_ui_core`] with a platform for the [Ratatui](https://ratatui.rs/) cell grid.
+
+use floating_ui_core::{
+    compute_position as compute_position_core, ComputePositionConfig as CoreComputePositionConfig,
+};
+pub use floating_ui_core::{
+    ComputePositionReturn, Derivable, DerivableFn, DetectOverflowOptions, ElementContext,
+    Middleware, MiddlewareData, MiddlewareReturn, MiddlewareState, MiddlewareWithOptions,
+    RootBoundary,
+};
+#[doc(no_inline)]
+pub use floating_ui_utils::{
+    AlignedPlacement, Alignment, Axis, ClientRect, Coords, Dimensions, ElementRects, Length,
+    Padding, PartialSideLength, Placement, Side, SideLength, Strategy, VirtualElement,
+};
+use ratatui::layout::Rect;
+
+pub use crate::element::*;
+pub use crate::middleware::*;
+pub use crate::platform::*;
+pub use crate::popup::*;
+pub use crate::types::*;
+
+mod element;
+mod middleware;
+mod platform;
+mod popup;
+mod types;
+
+/// Options for [`compute_position`].
+#[derive(Clone, Default)]
+pub struct ComputePositionConfig {
+    /// Where to place the floating element relative to the reference element.
+    ///
+    /// Defaults to [`Placement::Bottom`].
+    pub placement: Option<Placement>,
+
+    /// The strategy to use when positioning the floating element.
+    ///
+    /// Defaults to [`Strategy::Absolute`].
+    pub strategy: Option<Strategy>,
+
+    /// Vector of middleware objects to modify the positioning or provide data for rendering.
+    ///
+    /// Defaults to an empty vector.
+    pub middleware: Option<MiddlewareVec>,
+
+    /// The placement chosen by the previous computation, used by middleware with sticky placement to keep it stable.
+    ///
+    /// Defaults to [`Option::None`].
+    pub previous_placement: Option<Placement>,
+}
+
+impl ComputePositionConfig {
+    /// Set `placement` option.
+    pub fn placement(mut self, value: Placement) -> Self {
+        self.placement = Some(value);
+        self
+    }
+
+    /// Set `strategy` option.
+    pub fn strategy(mut self, value: Strategy) -> Self {
+        self.strategy = Some(value);
+        self
+    }
+
+    /// Set `middleware` option.
+    pub fn middleware(mut self, value: MiddlewareVec) -> Self {
+        self.middleware = Some(value);
+        self
+    }
+
+    /// Set `previous_placement` option.
+    pub fn previous_placement(mut self, value: Placement) -> Self {
+        self.previous_placement = Some(value);
+        self
+    }
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element in a terminal.
+///
+/// The `terminal` area is used as root boundary for middleware that detect overflow.
+pub fn compute_position(
+    reference: ElementOrVirtual,
+    floating: &Element,
+    terminal: Rect,
+    config: Option<ComputePositionConfig>,
+) -> ComputePositionReturn {
+    let config = config.unwrap_or_default();
+
+    compute_position_core(
+        reference,
+        floating,
+        CoreComputePositionConfig {
+            platform: &TuiPlatform::new(terminal),
+            placement: config.placement,
+            strategy: config.strategy,
+            middleware: config.middleware,
+            previous_placement: config.previous_placement,
+        },
+    )
+}
@@ -0,0 +1,61 @@
+pub use floating_ui_core::middleware::{
+    ApplyState, ArrowData, ArrowOptions, AutoPlacementData, AutoPlacementDataOverflow,
+    AutoPlacementOptions, AvoidData, AvoidDataOverlap, AvoidOptions, DefaultLimiter,
+    FallbackStrategy, FlipData, FlipDataOverflow, FlipOptions, HideData, HideOptions, HideStrategy,
+    HideThreshold, LimitShift, LimitShiftOffset, LimitShiftOffsetValues, LimitShiftOptions,
+    MatchReference, OffsetData, OffsetOptions, OffsetOptionsValues, PlacementScoreState,
+    PlacementScorer, ShiftData, ShiftOptions, SizeData, SizeOptions, StickyPlacementOptions,
+    TransformOrigin, TransformOriginData, ARROW_NAME, AUTO_PLACEMENT_NAME, AVOID_NAME, FLIP_NAME,
+    HIDE_NAME, OFFSET_NAME, SHIFT_NAME, SIZE_NAME, TRANSFORM_ORIGIN_NAME,
+};
+use floating_ui_core::middleware::{
+    Arrow as CoreArrow, AutoPlacement as CoreAutoPlacement, Avoid as CoreAvoid, Flip as CoreFlip,
+    Hide as CoreHide, Offset as CoreOffset, Shift as CoreShift, Size as CoreSize,
+};
+
+use crate::element::{Element, Terminal};
+
+/// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
+///
+/// See <https://floating-ui.com/docs/arrow> for the original documentation.
+pub type Arrow<'a> = CoreArrow<'a, Element, Terminal>;
+
+/// Optimizes the visibility of the floating element by choosing the placement that has the most space available automatically, without needing to specify a preferred placement.
+/// Alternative to [`Flip`].
+///
+/// See <https://floating-ui.com/docs/autoPlacement> for the original documentation.
+pub type AutoPlacement<'a> = CoreAutoPlacement<'a, Element, Terminal>;
+
+/// Avoids obstacles, such as other popups, by shifting the floating element along its alignment axis
+/// or by changing its placement, while respecting the terminal area.
+pub type Avoid<'a> = CoreAvoid<'a, Element, Terminal>;
+
+/// Optimizes the visibility of the floating element by flipping the `placement` in order to keep it in view when the preferred placement(s) will overflow the clipping boundary.
+/// Alternative to [`AutoPlacement`].
+///
+/// See <https://floating-ui.com/docs/flip> for the original documentation.
+pub type Flip<'a> = CoreFlip<'a, Element, Terminal>;
+
+/// Provides data to hide the floating element in applicable situations,
+/// such as when it is not in the same clipping context as the reference element.
+///
+/// See <https://floating-ui.com/docs/hide> for the original documentation.
+pub type Hide<'a> = CoreHide<'a, Element, Terminal>;
+
+/// Modifies the placement by translating the floating element along the specified axes.
+///
+/// See <https://floating-ui.com/docs/offset> for the original documentation.
+pub type Offset<'a> = CoreOffset<'a, Element, Terminal>;
+
+/// Optimizes the visibility of the floating element by shifting it in order to keep it in view when it will overflow the clipping boundary.
+///
+/// See <https://floating-ui.com/docs/shift> for the original documentation.
+pub type Shift<'a> = CoreShift<'a, Element, Terminal>;
+
+/// Provides data that allows you to change the size of the floating element -
+/// for instance, prevent it from overflowing the clipping boundary or match the width of the reference element.
+///
+/// Use [`Element::set_size`] in [`SizeOptions::apply`] to resize the floating element.
+///
+/// See <https://floating-ui.com/docs/size> for the original documentation.
+pub type Size<'a> = CoreSize<'a, Element, Terminal>;
@@ -0,0 +1,135 @@
+use floating_ui_core::{
+    middleware::SizeData, Boundary, GetClippingRectArgs, GetElementRectsArgs, Platform,
+    RootBoundary,
+};
+use floating_ui_utils::{Dimensions, ElementOrVirtual, ElementRects, Length, Rect as FloatingRect};
+use ratatui::layout::Rect;
+
+use crate::element::{Element, Terminal};
+
+/// Platform to position floating elements in a terminal cell grid.
+///
+/// The terminal area is used as root boundary and widget areas are used as clipping rects.
+#[derive(Clone, Copy, Debug)]
+pub struct TuiPlatform {
+    pub terminal: Rect,
+}
+
+impl TuiPlatform {
+    pub fn new(terminal: Rect) -> Self {
+        TuiPlatform { terminal }
+    }
+}
+
+/// Resolves a length of the cell grid, since terminals have no minimum and maximum size constraints.
+fn resolve_length(length: u16, resolved: Option<f64>, min: Option<f64>, max: Option<f64>) -> u16 {
+    let mut length = resolved.unwrap_or(length as f64);
+    if let Some(max) = max {
+        length = length.min(max);
+    }
+    if let Some(min) = min {
+        length = length.max(min);
+    }
+
+    length.clamp(0.0, u16::MAX as f64) as u16
+}
+
+pub(crate) fn to_floating_rect(area: Rect) -> FloatingRect {
+    FloatingRect {
+        x: area.x as f64,
+        y: area.y as f64,
+        width: area.width as f64,
+        height: area.height as f64,
+    }
+}
+
+fn intersect(rect: FloatingRect, other: &FloatingRect) -> FloatingRect {
+    let left = rect.x.max(other.x);
+    let top = rect.y.max(other.y);
+    let right = (rect.x + rect.width).min(other.x + other.width);
+    let bottom = (rect.y + rect.height).min(other.y + other.height);
+
+    FloatingRect {
+        x: left,
+        y: top,
+        width: (right - left).max(0.0),
+        height: (bottom - top).max(0.0),
+    }
+}
+
+impl Platform<Element, Terminal> for TuiPlatform {
+    fn get_element_rects(&self, args: GetElementRectsArgs<Element>) -> ElementRects {
+        let reference = match args.reference {
+            ElementOrVirtual::Element(element) => to_floating_rect(element.area()),
+            ElementOrVirtual::VirtualElement(virtual_element) => {
+                let client_rect = virtual_element.get_bounding_client_rect();
+
+                FloatingRect {
+                    x: client_rect.x,
+                    y: client_rect.y,
+                    width: client_rect.width,
+                    height: client_rect.height,
+                }
+            }
+        };
+        let floating = args.floating.area();
+
+        ElementRects {
+            reference,
+            floating: FloatingRect {
+                x: 0.0,
+                y: 0.0,
+                width: floating.width as f64,
+                height: floating.height as f64,
+            },
+        }
+    }
+
+    fn get_clipping_rect(&self, args: GetClippingRectArgs<Element>) -> FloatingRect {
+        let root_boundary = match args.root_boundary {
+            RootBoundary::Viewport | RootBoundary::Document => to_floating_rect(self.terminal),
+            RootBoundary::Rect(rect) => rect,
+        };
+
+        match args.boundary {
+            Boundary::ClippingAncestors => root_boundary,
+            Boundary::Element(element) => {
+                intersect(to_floating_rect(element.area()), &root_boundary)
+            }
+            Boundary::Elements(elements) => elements.iter().fold(root_boundary, |rect, element| {
+                intersect(rect, &to_floating_rect(element.area()))
+            }),
+        }
+    }
+
+    fn get_dimensions(&self, element: &Element) -> Dimensions {
+        let area = element.area();
+
+        Dimensions {
+            width: area.width as f64,
+            height: area.height as f64,
+        }
+    }
+
+    fn get_document_element(&self, _element: &Element) -> Option<Element> {
+        Some(Element::new(self.terminal))
+    }
+
+    fn get_client_length(&self, element: &Element, length: Length) -> Option<f64> {
+        let area = element.area();
+
+        Some(match length {
+            Length::Width => area.width as f64,
+            Length::Height => area.height as f64,
+        })
+    }
+
+    fn apply_size(&self, element: &Element, data: &SizeData) {
+        let area = element.area();
+
+        element.set_size(
+            resolve_length(area.width, data.width, data.min_width, data.max_width),
+            resolve_length(area.height, data.height, data.min_height, data.max_height),
+        );
+    }
+}
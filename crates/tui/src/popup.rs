@@ -0,0 +1,101 @@
+use ratatui::layout::Rect;
+
+use crate::{compute_position, ComputePositionConfig, ComputePositionReturn, Element};
+
+/// Round a computed position to the terminal cell grid and return the area of the floating element.
+///
+/// The area is clipped to the terminal, so it can be rendered directly.
+pub fn round_to_cells(
+    position: &ComputePositionReturn,
+    floating: &Element,
+    terminal: Rect,
+) -> Rect {
+    let area = floating.area();
+    let x = position
+        .x
+        .round()
+        .clamp(terminal.left() as f64, terminal.right() as f64) as u16;
+    let y = position
+        .y
+        .round()
+        .clamp(terminal.top() as f64, terminal.bottom() as f64) as u16;
+
+    Rect::new(x, y, area.width, area.height).intersection(terminal)
+}
+
+/// Computes the area of a popup with the given size next to a reference area, such as a widget or the cursor.
+///
+/// Middleware like [`Size`][`crate::Size`] can resize the popup through [`Element::set_size`], the returned area has the final size.
+pub fn compute_popup_area(
+    reference: Rect,
+    width: u16,
+    height: u16,
+    terminal: Rect,
+    config: Option<ComputePositionConfig>,
+) -> Rect {
+    let reference = Element::new(reference);
+    let floating = Element::new(Rect::new(0, 0, width, height));
+
+    let position = compute_position((&reference).into(), &floating, terminal, config);
+
+    round_to_cells(&position, &floating, terminal)
+}
+
+#[cfg(test)]
+mod tests {
+    use floating_ui_utils::Placement;
+
+    use super::*;
+    use crate::{ApplyState, Flip, FlipOptions, Shift, ShiftOptions, Size, SizeOptions, Terminal};
+
+    const TERMINAL: Rect = Rect::new(0, 0, 80, 24);
+
+    #[test]
+    fn test_placement() {
+        assert_eq!(
+            compute_popup_area(Rect::new(10, 5, 10, 1), 6, 3, TERMINAL, None),
+            Rect::new(12, 6, 6, 3)
+        );
+    }
+
+    #[test]
+    fn test_flip_and_shift() {
+        let config = ComputePositionConfig::default()
+            .placement(Placement::Bottom)
+            .middleware(vec![
+                Box::new(Flip::new(FlipOptions::default())),
+                Box::new(Shift::new(ShiftOptions::default())),
+            ]);
+
+        assert_eq!(
+            compute_popup_area(Rect::new(74, 22, 4, 1), 10, 4, TERMINAL, Some(config)),
+            Rect::new(70, 18, 10, 4)
+        );
+    }
+
+    #[test]
+    fn test_size() {
+        let apply: &'static dyn Fn(ApplyState<Element, Terminal>) =
+            &|ApplyState {
+                  state,
+                  available_width,
+                  available_height,
+              }| {
+                let area = state.elements.floating.area();
+                state.elements.floating.set_size(
+                    area.width.min(available_width as u16),
+                    area.height.min(available_height as u16),
+                );
+            };
+        let config = ComputePositionConfig::default()
+            .placement(Placement::Bottom)
+            .middleware(vec![Box::new(Size::new(
+                SizeOptions::default().apply(apply),
+            ))]);
+
+        assert_eq!(
+            compute_popup_area(Rect::new(0, 19, 10, 1), 10, 8, TERMINAL, Some(config)),
+            Rect::new(0, 20, 10, 4)
+        );
+    }
+}
@@ -13,7 +13,7 @@ pub enum Alignment {
 }
 
 /// 方位
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Side {
     /// 上
     Top,
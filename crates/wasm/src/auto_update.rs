@@ -0,0 +1,55 @@
+use std::rc::Rc;
+
+use floating_ui_dom::{auto_update as dom_auto_update, AutoUpdateOptions};
+use js_sys::Function;
+use wasm_bindgen::{closure::Closure, prelude::wasm_bindgen, JsError, JsValue};
+use web_sys::{console, Element};
+
+use crate::{options::get_bool, virtual_element::to_reference};
+
+/// Automatically updates the position of the floating element when necessary.
+///
+/// The `options` object accepts `ancestorScroll`, `ancestorResize`, `elementResize`, `layoutShift` and `animationFrame`.
+/// Returns a cleanup function that should be invoked when the floating element is removed from the DOM or hidden from the screen.
+///
+/// See <https://floating-ui.com/docs/autoUpdate> for the original documentation.
+#[wasm_bindgen(js_name = autoUpdate)]
+pub fn auto_update(
+    reference: JsValue,
+    floating: Element,
+    update: Function,
+    options: JsValue,
+) -> Result<JsValue, JsError> {
+    let reference = to_reference(reference)?;
+
+    let mut auto_update_options = AutoUpdateOptions::default();
+    if let Some(ancestor_scroll) = get_bool(&options, "ancestorScroll") {
+        auto_update_options = auto_update_options.ancestor_scroll(ancestor_scroll);
+    }
+    if let Some(ancestor_resize) = get_bool(&options, "ancestorResize") {
+        auto_update_options = auto_update_options.ancestor_resize(ancestor_resize);
+    }
+    if let Some(element_resize) = get_bool(&options, "elementResize") {
+        auto_update_options = auto_update_options.element_resize(element_resize);
+    }
+    if let Some(layout_shift) = get_bool(&options, "layoutShift") {
+        auto_update_options = auto_update_options.layout_shift(layout_shift);
+    }
+    if let Some(animation_frame) = get_bool(&options, "animationFrame") {
+        auto_update_options = auto_update_options.animation_frame(animation_frame);
+    }
+
+    let cleanup = dom_auto_update(
+        (&reference).into(),
+        &floating,
+        Rc::new(move || {
+            // Errors of the update function are logged, so later updates still run.
+            if let Err(error) = update.call0(&JsValue::UNDEFINED) {
+                console::error_1(&error);
+            }
+        }),
+        auto_update_options,
+    );
+
+    Ok(Closure::once_into_js(cleanup))
+}
@@ -0,0 +1,92 @@
+use floating_ui_dom::{compute_position as dom_compute_position, ComputePositionConfig};
+use js_sys::{Object, Promise};
+use wasm_bindgen::{prelude::wasm_bindgen, JsError, JsValue};
+use web_sys::Element;
+
+use crate::{
+    middleware::create_middleware,
+    options::{
+        format_middleware_data, format_placement, format_strategy, get_array, get_placement,
+        get_strategy, get_string, set, to_js_value,
+    },
+    virtual_element::to_reference,
+};
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a given reference element.
+///
+/// The `options` object accepts `placement`, `strategy` and `middleware`, like the JavaScript API,
+/// and `previousPlacement` for middleware with sticky placement.
+/// Resolves to an object with `x`, `y`, `placement`, `strategy` and `middlewareData`.
+///
+/// See <https://floating-ui.com/docs/computePosition> for the original documentation.
+#[wasm_bindgen(js_name = computePosition)]
+pub fn compute_position(
+    reference: JsValue,
+    floating: Element,
+    options: JsValue,
+) -> Result<Promise, JsError> {
+    compute_position_sync(reference, &floating, &options).map(|result| Promise::resolve(&result))
+}
+
+pub(crate) fn compute_position_sync(
+    reference: JsValue,
+    floating: &Element,
+    options: &JsValue,
+) -> Result<JsValue, JsError> {
+    let reference = to_reference(reference)?;
+
+    let middleware_array = get_array(options, "middleware");
+    let middleware_names: Vec<String> = middleware_array
+        .iter()
+        .flat_map(|middleware| middleware.iter())
+        .filter_map(|middleware| get_string(&middleware, "name"))
+        .collect();
+    let middleware = middleware_array
+        .map(|middleware| {
+            middleware
+                .iter()
+                .filter(|middleware| !middleware.is_falsy())
+                .map(|middleware| create_middleware(&middleware))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let position = dom_compute_position(
+        (&reference).into(),
+        floating,
+        Some(ComputePositionConfig {
+            placement: get_placement(options, "placement")?,
+            strategy: get_strategy(options, "strategy")?,
+            middleware,
+            previous_placement: get_placement(options, "previousPlacement")?,
+        }),
+    );
+
+    let middleware_data = Object::new();
+    for name in middleware_names {
+        if let Some(data) = position.middleware_data.get(&name) {
+            set(
+                &middleware_data,
+                &name,
+                &to_js_value(&format_middleware_data(data.clone())),
+            );
+        }
+    }
+
+    let result = Object::new();
+    set(&result, "x", &JsValue::from_f64(position.x));
+    set(&result, "y", &JsValue::from_f64(position.y));
+    set(
+        &result,
+        "placement",
+        &JsValue::from_str(format_placement(position.placement)),
+    );
+    set(
+        &result,
+        "strategy",
+        &JsValue::from_str(format_strategy(position.strategy)),
+    );
+    set(&result, "middlewareData", &middleware_data.into());
+
+    Ok(result.into())
+}
@@ -0,0 +1,17 @@
+//! Rust port of [Floating UI](https://floating-ui.com/) for JavaScript through WebAssembly.
+//!
+//! Exposes `computePosition`, `autoUpdate` and the built-in middleware of [`floating_ui_dom`] to JavaScript.
+//! Custom middleware written in Rust can be made available to JavaScript with [`register_middleware`].
+//!
+//! See [@floating-ui/dom](https://floating-ui.com/docs/computePosition) for the original JavaScript API.
+
+pub use auto_update::*;
+pub use compute_position::*;
+pub use middleware::*;
+pub use virtual_element::*;
+
+mod auto_update;
+mod compute_position;
+mod middleware;
+mod options;
+mod virtual_element;
@@ -0,0 +1,438 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use floating_ui_dom::{
+    Arrow, ArrowOptions, AutoPlacement, AutoPlacementOptions, Avoid, AvoidOptions,
+    DetectOverflowOptions, Flip, FlipOptions, Hide, HideOptions, HideStrategy, HideThreshold,
+    Inline, InlineOptions, Inner, InnerOptions, MatchReference, Middleware, Occlusion,
+    OcclusionOptions, Offset, OffsetOptions, OffsetOptionsValues, PlacementScoreState,
+    PlacementScorer, Rect, Shift, ShiftOptions, Size, SizeOptions, StickyPlacementOptions,
+    TransformOrigin, ARROW_NAME, AUTO_PLACEMENT_NAME, AVOID_NAME, FLIP_NAME, HIDE_NAME,
+    INLINE_NAME, INNER_NAME, OCCLUSION_NAME, OFFSET_NAME, SHIFT_NAME, SIZE_NAME,
+    TRANSFORM_ORIGIN_NAME,
+};
+use js_sys::{Array, Function, Object};
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsError, JsValue};
+use web_sys::{Element, Window};
+
+use crate::options::{
+    format_placement, get, get_alignment, get_array, get_bool, get_f64, get_padding,
+    get_placements, get_string, set,
+};
+
+pub type MiddlewareFactoryFn =
+    dyn Fn(&JsValue) -> Result<Box<dyn Middleware<Element, Window>>, JsError>;
+
+thread_local! {
+    static MIDDLEWARE_FACTORIES: RefCell<HashMap<String, Rc<MiddlewareFactoryFn>>> = RefCell::new(HashMap::new());
+}
+
+/// Register a custom middleware, so JavaScript can use it with a `{ name, options }` object.
+///
+/// The factory is called with the `options` of the object on every position computation.
+pub fn register_middleware(
+    name: &str,
+    factory: impl Fn(&JsValue) -> Result<Box<dyn Middleware<Element, Window>>, JsError> + 'static,
+) {
+    MIDDLEWARE_FACTORIES.with(|factories| {
+        factories
+            .borrow_mut()
+            .insert(name.to_string(), Rc::new(factory));
+    });
+}
+
+fn descriptor(name: &str, options: JsValue) -> JsValue {
+    let object = Object::new();
+    set(&object, "name", &JsValue::from_str(name));
+    set(&object, "options", &options);
+    object.into()
+}
+
+fn detect_overflow_options(options: &JsValue) -> DetectOverflowOptions<Element> {
+    let mut detect_overflow = DetectOverflowOptions::default();
+    if let Some(padding) = get_padding(options, "padding") {
+        detect_overflow = detect_overflow.padding(padding);
+    }
+    if let Some(alt_boundary) = get_bool(options, "altBoundary") {
+        detect_overflow = detect_overflow.alt_boundary(alt_boundary);
+    }
+    detect_overflow
+}
+
+fn sticky_placement_options(options: &JsValue) -> Option<StickyPlacementOptions> {
+    let sticky = get(options, "stickyPlacement")?;
+    if sticky.is_falsy() {
+        return None;
+    }
+
+    let mut sticky_placement = StickyPlacementOptions::default();
+    if let Some(threshold) = get_f64(&sticky, "threshold") {
+        sticky_placement = sticky_placement.threshold(threshold);
+    }
+    if let Some(margin) = get_f64(&sticky, "margin") {
+        sticky_placement = sticky_placement.margin(margin);
+    }
+    Some(sticky_placement)
+}
+
+fn scorer(options: &JsValue) -> Option<Box<dyn PlacementScorer>> {
+    let function = get(options, "scorer")?.dyn_into::<Function>().ok()?;
+
+    Some(Box::new(move |state: PlacementScoreState| {
+        let object = Object::new();
+        set(
+            &object,
+            "placement",
+            &JsValue::from_str(format_placement(state.placement)),
+        );
+        set(
+            &object,
+            "overflows",
+            &state
+                .overflows
+                .iter()
+                .map(|overflow| JsValue::from_f64(*overflow))
+                .collect::<Array>(),
+        );
+        set(
+            &object,
+            "availableSpace",
+            &JsValue::from_f64(state.available_space),
+        );
+
+        function
+            .call1(&JsValue::NULL, &object)
+            .ok()
+            .and_then(|score| score.as_f64())
+            .unwrap_or(f64::INFINITY)
+    }))
+}
+
+/// Create the middleware of a `{ name, options }` object.
+pub fn create_middleware(
+    middleware: &JsValue,
+) -> Result<Box<dyn Middleware<Element, Window>>, JsError> {
+    let name = get_string(middleware, "name")
+        .ok_or_else(|| JsError::new("Middleware should have a `name`."))?;
+    let options = get(middleware, "options").unwrap_or(JsValue::UNDEFINED);
+
+    Ok(match name.as_str() {
+        ARROW_NAME => {
+            let element = get(&options, "element")
+                .and_then(|element| element.dyn_into::<Element>().ok())
+                .ok_or_else(|| JsError::new("Arrow middleware should have an `element`."))?;
+            let mut arrow_options = ArrowOptions::new(element);
+            if let Some(padding) = get_padding(&options, "padding") {
+                arrow_options = arrow_options.padding(padding);
+            }
+            if let Some(tip_offset) = get_f64(&options, "tipOffset") {
+                arrow_options = arrow_options.tip_offset(tip_offset);
+            }
+            Box::new(Arrow::new(arrow_options))
+        }
+        AUTO_PLACEMENT_NAME => {
+            let mut auto_placement_options =
+                AutoPlacementOptions::default().detect_overflow(detect_overflow_options(&options));
+            if let Some(cross_axis) = get_bool(&options, "crossAxis") {
+                auto_placement_options = auto_placement_options.cross_axis(cross_axis);
+            }
+            if let Some(alignment) = get_alignment(&options, "alignment")? {
+                auto_placement_options = auto_placement_options.alignment(alignment);
+            }
+            if let Some(auto_alignment) = get_bool(&options, "autoAlignment") {
+                auto_placement_options = auto_placement_options.auto_alignment(auto_alignment);
+            }
+            if let Some(allowed_placements) = get_placements(&options, "allowedPlacements")? {
+                auto_placement_options =
+                    auto_placement_options.allowed_placements(allowed_placements);
+            }
+            if let Some(sticky_placement) = sticky_placement_options(&options) {
+                auto_placement_options = auto_placement_options.sticky_placement(sticky_placement);
+            }
+            if let Some(scorer) = scorer(&options) {
+                auto_placement_options = auto_placement_options.scorer(scorer);
+            }
+            Box::new(AutoPlacement::new(auto_placement_options))
+        }
+        AVOID_NAME => {
+            let obstacles = get_array(&options, "obstacles")
+                .map(|obstacles| {
+                    obstacles
+                        .iter()
+                        .map(|obstacle| Rect {
+                            x: get_f64(&obstacle, "x").unwrap_or(0.0),
+                            y: get_f64(&obstacle, "y").unwrap_or(0.0),
+                            width: get_f64(&obstacle, "width").unwrap_or(0.0),
+                            height: get_f64(&obstacle, "height").unwrap_or(0.0),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            let mut avoid_options =
+                AvoidOptions::new(obstacles).detect_overflow(detect_overflow_options(&options));
+            if let Some(padding) = get_f64(&options, "obstaclePadding") {
+                avoid_options = avoid_options.padding(padding);
+            }
+            if let Some(fallback_placements) = get_placements(&options, "fallbackPlacements")? {
+                avoid_options = avoid_options.fallback_placements(fallback_placements);
+            }
+            Box::new(Avoid::new(avoid_options))
+        }
+        FLIP_NAME => {
+            let mut flip_options =
+                FlipOptions::default().detect_overflow(detect_overflow_options(&options));
+            if let Some(main_axis) = get_bool(&options, "mainAxis") {
+                flip_options = flip_options.main_axis(main_axis);
+            }
+            if let Some(cross_axis) = get_bool(&options, "crossAxis") {
+                flip_options = flip_options.cross_axis(cross_axis);
+            }
+            if let Some(fallback_placements) = get_placements(&options, "fallbackPlacements")? {
+                flip_options = flip_options.fallback_placements(fallback_placements);
+            }
+            if let Some(direction) = get_alignment(&options, "fallbackAxisSideDirection")? {
+                flip_options = flip_options.fallback_axis_side_direction(direction);
+            }
+            if let Some(flip_alignment) = get_bool(&options, "flipAlignment") {
+                flip_options = flip_options.flip_alignment(flip_alignment);
+            }
+            if let Some(sticky_placement) = sticky_placement_options(&options) {
+                flip_options = flip_options.sticky_placement(sticky_placement);
+            }
+            if let Some(scorer) = scorer(&options) {
+                flip_options = flip_options.scorer(scorer);
+            }
+            Box::new(Flip::new(flip_options))
+        }
+        HIDE_NAME => {
+            let mut hide_options =
+                HideOptions::default().detect_overflow(detect_overflow_options(&options));
+            match get_string(&options, "strategy").as_deref() {
+                Some("referenceHidden") | None => {}
+                Some("escaped") => hide_options = hide_options.strategy(HideStrategy::Escaped),
+                Some("both") => hide_options = hide_options.strategy(HideStrategy::Both),
+                Some(strategy) => {
+                    return Err(JsError::new(&format!(
+                        "Unknown hide strategy `{strategy}`."
+                    )))
+                }
+            }
+            if let Some(threshold) = get(&options, "threshold") {
+                if let Some(ratio) = get_f64(&threshold, "ratio") {
+                    hide_options = hide_options.threshold(HideThreshold::Ratio(ratio));
+                } else if let Some(pixels) = get_f64(&threshold, "pixels") {
+                    hide_options = hide_options.threshold(HideThreshold::Pixels(pixels));
+                }
+            }
+            Box::new(Hide::new(hide_options))
+        }
+        INLINE_NAME => {
+            let mut inline_options = InlineOptions::default();
+            if let Some(x) = get_f64(&options, "x") {
+                inline_options = inline_options.x(x);
+            }
+            if let Some(y) = get_f64(&options, "y") {
+                inline_options = inline_options.y(y);
+            }
+            if let Some(padding) = get_padding(&options, "padding") {
+                inline_options = inline_options.padding(padding);
+            }
+            Box::new(Inline::new(inline_options))
+        }
+        INNER_NAME => {
+            let list = get_array(&options, "list")
+                .ok_or_else(|| JsError::new("Inner middleware should have a `list`."))?
+                .iter()
+                .map(|item| {
+                    item.dyn_into::<Element>()
+                        .map_err(|_| JsError::new("Inner list item should be an element."))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut inner_options =
+                InnerOptions::new(list).detect_overflow(detect_overflow_options(&options));
+            if let Some(index) = get_f64(&options, "index") {
+                inner_options = inner_options.index(index as usize);
+            }
+            if let Some(scroll_element) = get(&options, "scrollElement")
+                .and_then(|element| element.dyn_into::<Element>().ok())
+            {
+                inner_options = inner_options.scroll_element(scroll_element);
+            }
+            if let Some(offset) = get_f64(&options, "offset") {
+                inner_options = inner_options.offset(offset);
+            }
+            if let Some(min_items_visible) = get_f64(&options, "minItemsVisible") {
+                inner_options = inner_options.min_items_visible(min_items_visible as usize);
+            }
+            if let Some(threshold) = get_f64(&options, "referenceOverflowThreshold") {
+                inner_options = inner_options.reference_overflow_threshold(threshold);
+            }
+            Box::new(Inner::new(inner_options))
+        }
+        OCCLUSION_NAME => {
+            let mut occlusion_options = OcclusionOptions::default();
+            if let Some(columns) = get_f64(&options, "columns") {
+                occlusion_options = occlusion_options.columns(columns as u32);
+            }
+            if let Some(rows) = get_f64(&options, "rows") {
+                occlusion_options = occlusion_options.rows(rows as u32);
+            }
+            Box::new(Occlusion::new(occlusion_options))
+        }
+        OFFSET_NAME => {
+            let offset_options = match options.as_f64() {
+                Some(value) => OffsetOptions::Value(value),
+                None => OffsetOptions::Values(OffsetOptionsValues {
+                    main_axis: get_f64(&options, "mainAxis"),
+                    cross_axis: get_f64(&options, "crossAxis"),
+                    alignment_axis: get_f64(&options, "alignmentAxis"),
+                }),
+            };
+            Box::new(Offset::new(offset_options))
+        }
+        SHIFT_NAME => {
+            let mut shift_options =
+                ShiftOptions::default().detect_overflow(detect_overflow_options(&options));
+            if let Some(main_axis) = get_bool(&options, "mainAxis") {
+                shift_options = shift_options.main_axis(main_axis);
+            }
+            if let Some(cross_axis) = get_bool(&options, "crossAxis") {
+                shift_options = shift_options.cross_axis(cross_axis);
+            }
+            Box::new(Shift::new(shift_options))
+        }
+        SIZE_NAME => {
+            let mut size_options =
+                SizeOptions::default().detect_overflow(detect_overflow_options(&options));
+            match get_string(&options, "matchReference").as_deref() {
+                Some("width") => size_options = size_options.match_reference(MatchReference::Width),
+                Some("height") => {
+                    size_options = size_options.match_reference(MatchReference::Height)
+                }
+                Some("both") => size_options = size_options.match_reference(MatchReference::Both),
+                None => {}
+                Some(match_reference) => {
+                    return Err(JsError::new(&format!(
+                        "Unknown size match reference `{match_reference}`."
+                    )))
+                }
+            }
+            if let Some(min_width) = get_f64(&options, "minWidth") {
+                size_options = size_options.min_width(min_width);
+            }
+            if let Some(min_height) = get_f64(&options, "minHeight") {
+                size_options = size_options.min_height(min_height);
+            }
+            if let Some(max_width) = get_f64(&options, "maxWidth") {
+                size_options = size_options.max_width(max_width);
+            }
+            if let Some(max_height) = get_f64(&options, "maxHeight") {
+                size_options = size_options.max_height(max_height);
+            }
+            Box::new(Size::new(size_options))
+        }
+        TRANSFORM_ORIGIN_NAME => Box::new(TransformOrigin::new()),
+        name => {
+            let factory = MIDDLEWARE_FACTORIES
+                .with(|factories| factories.borrow().get(name).cloned())
+                .ok_or_else(|| JsError::new(&format!("Unknown middleware `{name}`.")))?;
+            factory(&options)?
+        }
+    })
+}
+
+/// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
+///
+/// See <https://floating-ui.com/docs/arrow> for the original documentation.
+#[wasm_bindgen]
+pub fn arrow(options: JsValue) -> JsValue {
+    descriptor(ARROW_NAME, options)
+}
+
+/// Optimizes the visibility of the floating element by choosing the placement that has the most space available automatically.
+///
+/// See <https://floating-ui.com/docs/autoPlacement> for the original documentation.
+#[wasm_bindgen(js_name = autoPlacement)]
+pub fn auto_placement(options: JsValue) -> JsValue {
+    descriptor(AUTO_PLACEMENT_NAME, options)
+}
+
+/// Avoids obstacles, such as other floating elements, by shifting the floating element or by changing its placement.
+///
+/// The `obstacles` option is an array of `{ x, y, width, height }` rects in the same coordinate space as the result of `computePosition`,
+/// and `obstaclePadding` is the minimum distance to the obstacles.
+#[wasm_bindgen]
+pub fn avoid(options: JsValue) -> JsValue {
+    descriptor(AVOID_NAME, options)
+}
+
+/// Optimizes the visibility of the floating element by flipping the `placement` in order to keep it in view.
+///
+/// See <https://floating-ui.com/docs/flip> for the original documentation.
+#[wasm_bindgen]
+pub fn flip(options: JsValue) -> JsValue {
+    descriptor(FLIP_NAME, options)
+}
+
+/// Provides data to hide the floating element in applicable situations.
+///
+/// See <https://floating-ui.com/docs/hide> for the original documentation.
+#[wasm_bindgen]
+pub fn hide(options: JsValue) -> JsValue {
+    descriptor(HIDE_NAME, options)
+}
+
+/// Provides improved positioning for inline reference elements that can span over multiple lines.
+///
+/// See <https://floating-ui.com/docs/inline> for the original documentation.
+#[wasm_bindgen]
+pub fn inline(options: JsValue) -> JsValue {
+    descriptor(INLINE_NAME, options)
+}
+
+/// Positions the floating element over the reference element, so the selected item of the `list` option is aligned with it, like a native `select`.
+///
+/// The maximum height and scroll position of the list are stored in the middleware data.
+///
+/// See <https://floating-ui.com/docs/inner> for the original documentation.
+#[wasm_bindgen]
+pub fn inner(options: JsValue) -> JsValue {
+    descriptor(INNER_NAME, options)
+}
+
+/// Provides data to hide the floating element when the reference element is covered by another element.
+#[wasm_bindgen]
+pub fn occlusion(options: JsValue) -> JsValue {
+    descriptor(OCCLUSION_NAME, options)
+}
+
+/// Modifies the placement by translating the floating element along the specified axes.
+///
+/// See <https://floating-ui.com/docs/offset> for the original documentation.
+#[wasm_bindgen]
+pub fn offset(options: JsValue) -> JsValue {
+    descriptor(OFFSET_NAME, options)
+}
+
+/// Optimizes the visibility of the floating element by shifting it in order to keep it in view.
+///
+/// See <https://floating-ui.com/docs/shift> for the original documentation.
+#[wasm_bindgen]
+pub fn shift(options: JsValue) -> JsValue {
+    descriptor(SHIFT_NAME, options)
+}
+
+/// Changes the size of the floating element to match the reference element and to fit within the clipping boundary.
+///
+/// The resolved sizes are applied to the floating element and stored in the middleware data.
+///
+/// See <https://floating-ui.com/docs/size> for the original documentation.
+#[wasm_bindgen]
+pub fn size(options: JsValue) -> JsValue {
+    descriptor(SIZE_NAME, options)
+}
+
+/// Provides the CSS `transform-origin` of the floating element, so it appears to scale out of the reference element.
+#[wasm_bindgen(js_name = transformOrigin)]
+pub fn transform_origin() -> JsValue {
+    descriptor(TRANSFORM_ORIGIN_NAME, JsValue::UNDEFINED)
+}
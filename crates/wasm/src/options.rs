@@ -0,0 +1,230 @@
+use floating_ui_dom::{Alignment, Padding, PartialSideLength, Placement, Strategy};
+use js_sys::{Array, Object, Reflect};
+use wasm_bindgen::{JsCast, JsError, JsValue};
+
+/// Read the property `key` of a JS options object, ignoring `undefined` and `null`.
+pub fn get(options: &JsValue, key: &str) -> Option<JsValue> {
+    if !options.is_object() {
+        return None;
+    }
+
+    Reflect::get(options, &JsValue::from_str(key))
+        .ok()
+        .filter(|value| !value.is_undefined() && !value.is_null())
+}
+
+pub fn get_bool(options: &JsValue, key: &str) -> Option<bool> {
+    get(options, key).and_then(|value| value.as_bool())
+}
+
+pub fn get_f64(options: &JsValue, key: &str) -> Option<f64> {
+    get(options, key).and_then(|value| value.as_f64())
+}
+
+pub fn get_string(options: &JsValue, key: &str) -> Option<String> {
+    get(options, key).and_then(|value| value.as_string())
+}
+
+pub fn get_array(options: &JsValue, key: &str) -> Option<Array> {
+    get(options, key).and_then(|value| value.dyn_into::<Array>().ok())
+}
+
+pub fn get_placement(options: &JsValue, key: &str) -> Result<Option<Placement>, JsError> {
+    get_string(options, key)
+        .map(|value| parse_placement(&value))
+        .transpose()
+}
+
+pub fn get_placements(options: &JsValue, key: &str) -> Result<Option<Vec<Placement>>, JsError> {
+    get_array(options, key)
+        .map(|placements| {
+            placements
+                .iter()
+                .map(|placement| {
+                    placement
+                        .as_string()
+                        .ok_or_else(|| JsError::new("Placement should be a string."))
+                        .and_then(|placement| parse_placement(&placement))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+pub fn get_strategy(options: &JsValue, key: &str) -> Result<Option<Strategy>, JsError> {
+    get_string(options, key)
+        .map(|value| parse_strategy(&value))
+        .transpose()
+}
+
+pub fn get_alignment(options: &JsValue, key: &str) -> Result<Option<Alignment>, JsError> {
+    get_string(options, key)
+        .map(|value| parse_alignment(&value))
+        .transpose()
+}
+
+/// Read a padding, which is either a number or an object with `top`, `right`, `bottom` and `left`.
+pub fn get_padding(options: &JsValue, key: &str) -> Option<Padding> {
+    get(options, key).map(|value| match value.as_f64() {
+        Some(padding) => Padding::All(padding),
+        None => Padding::PerSide(PartialSideLength {
+            top: get_f64(&value, "top"),
+            right: get_f64(&value, "right"),
+            bottom: get_f64(&value, "bottom"),
+            left: get_f64(&value, "left"),
+        }),
+    })
+}
+
+pub fn parse_placement(value: &str) -> Result<Placement, JsError> {
+    match value {
+        "top" => Ok(Placement::Top),
+        "top-start" => Ok(Placement::TopStart),
+        "top-end" => Ok(Placement::TopEnd),
+        "right" => Ok(Placement::Right),
+        "right-start" => Ok(Placement::RightStart),
+        "right-end" => Ok(Placement::RightEnd),
+        "bottom" => Ok(Placement::Bottom),
+        "bottom-start" => Ok(Placement::BottomStart),
+        "bottom-end" => Ok(Placement::BottomEnd),
+        "left" => Ok(Placement::Left),
+        "left-start" => Ok(Placement::LeftStart),
+        "left-end" => Ok(Placement::LeftEnd),
+        _ => Err(JsError::new(&format!("Unknown placement `{value}`."))),
+    }
+}
+
+pub fn format_placement(placement: Placement) -> &'static str {
+    match placement {
+        Placement::Top => "top",
+        Placement::TopStart => "top-start",
+        Placement::TopEnd => "top-end",
+        Placement::Right => "right",
+        Placement::RightStart => "right-start",
+        Placement::RightEnd => "right-end",
+        Placement::Bottom => "bottom",
+        Placement::BottomStart => "bottom-start",
+        Placement::BottomEnd => "bottom-end",
+        Placement::Left => "left",
+        Placement::LeftStart => "left-start",
+        Placement::LeftEnd => "left-end",
+    }
+}
+
+pub fn parse_strategy(value: &str) -> Result<Strategy, JsError> {
+    match value {
+        "absolute" => Ok(Strategy::Absolute),
+        "fixed" => Ok(Strategy::Fixed),
+        _ => Err(JsError::new(&format!("Unknown strategy `{value}`."))),
+    }
+}
+
+pub fn format_strategy(strategy: Strategy) -> &'static str {
+    match strategy {
+        Strategy::Absolute => "absolute",
+        Strategy::Fixed => "fixed",
+    }
+}
+
+pub fn parse_alignment(value: &str) -> Result<Alignment, JsError> {
+    match value {
+        "start" => Ok(Alignment::Start),
+        "end" => Ok(Alignment::End),
+        _ => Err(JsError::new(&format!("Unknown alignment `{value}`."))),
+    }
+}
+
+/// Convert middleware data to the format of the JavaScript API.
+///
+/// Snake case keys are converted to camel case and placement and side values are formatted like [`format_placement`].
+pub fn format_middleware_data(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (to_camel_case(&key), format_middleware_data(value)))
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(format_middleware_data).collect())
+        }
+        // Sides serialize like the placements without alignment, so they are formatted the same way.
+        serde_json::Value::String(value) => {
+            match serde_json::from_value::<Placement>(serde_json::Value::String(value.clone())) {
+                Ok(placement) => serde_json::Value::String(format_placement(placement).into()),
+                Err(_) => serde_json::Value::String(value),
+            }
+        }
+        value => value,
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut result = String::with_capacity(key.len());
+    let mut uppercase = false;
+
+    for character in key.chars() {
+        if character == '_' {
+            uppercase = true;
+        } else if uppercase {
+            result.extend(character.to_uppercase());
+            uppercase = false;
+        } else {
+            result.push(character);
+        }
+    }
+
+    result
+}
+
+/// Convert a JSON value to a JS value.
+pub fn to_js_value(value: &serde_json::Value) -> JsValue {
+    js_sys::JSON::parse(&value.to_string()).unwrap_or(JsValue::UNDEFINED)
+}
+
+pub fn set(object: &Object, key: &str, value: &JsValue) {
+    Reflect::set(object, &JsValue::from_str(key), value).expect("Object should be extensible.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placement_roundtrip() {
+        for placement in [
+            Placement::Top,
+            Placement::RightStart,
+            Placement::BottomEnd,
+            Placement::Left,
+        ] {
+            assert_eq!(
+                parse_placement(format_placement(placement)).ok(),
+                Some(placement)
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_middleware_data() {
+        let value = serde_json::json!({
+            "center_offset": 0.0,
+            "side": "Bottom",
+            "overflows": [
+                { "placement": "Top", "alignment_offset": 1.0 },
+                { "placement": "RightStart", "alignment_offset": 2.0 },
+            ],
+        });
+
+        assert_eq!(
+            format_middleware_data(value),
+            serde_json::json!({
+                "centerOffset": 0.0,
+                "side": "bottom",
+                "overflows": [
+                    { "placement": "top", "alignmentOffset": 1.0 },
+                    { "placement": "right-start", "alignmentOffset": 2.0 },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use floating_ui_dom::{ClientRect, OwnedElementOrVirtual, VirtualElement};
+use js_sys::{Array, Function, Reflect};
+use wasm_bindgen::{JsCast, JsError, JsValue};
+use web_sys::Element;
+
+use crate::options::{get, get_f64};
+
+/// Virtual element implemented by a JavaScript object with `getBoundingClientRect`,
+/// and optionally `getClientRects` and `contextElement`.
+#[derive(Clone)]
+pub struct JsVirtualElement {
+    object: JsValue,
+}
+
+impl JsVirtualElement {
+    fn call(&self, name: &str) -> Option<JsValue> {
+        Reflect::get(&self.object, &JsValue::from_str(name))
+            .ok()
+            .and_then(|function| function.dyn_into::<Function>().ok())
+            .and_then(|function| function.call0(&self.object).ok())
+    }
+}
+
+fn to_client_rect(rect: &JsValue) -> ClientRect {
+    let x = get_f64(rect, "x")
+        .or_else(|| get_f64(rect, "left"))
+        .unwrap_or(0.0);
+    let y = get_f64(rect, "y")
+        .or_else(|| get_f64(rect, "top"))
+        .unwrap_or(0.0);
+    let width = get_f64(rect, "width").unwrap_or(0.0);
+    let height = get_f64(rect, "height").unwrap_or(0.0);
+
+    ClientRect {
+        x,
+        y,
+        width,
+        height,
+        top: y,
+        right: x + width,
+        bottom: y + height,
+        left: x,
+    }
+}
+
+impl VirtualElement<Element> for JsVirtualElement {
+    fn get_bounding_client_rect(&self) -> ClientRect {
+        self.call("getBoundingClientRect")
+            .map(|rect| to_client_rect(&rect))
+            .unwrap_or_else(|| to_client_rect(&JsValue::UNDEFINED))
+    }
+
+    fn get_client_rects(&self) -> Option<Vec<ClientRect>> {
+        self.call("getClientRects").map(|rects| {
+            Array::from(&rects)
+                .iter()
+                .map(|rect| to_client_rect(&rect))
+                .collect()
+        })
+    }
+
+    fn context_element(&self) -> Option<Element> {
+        get(&self.object, "contextElement").and_then(|element| element.dyn_into::<Element>().ok())
+    }
+}
+
+/// Convert a JavaScript reference, either an element or a virtual element, to a reference.
+pub fn to_reference(reference: JsValue) -> Result<OwnedElementOrVirtual, JsError> {
+    if reference.is_instance_of::<Element>() {
+        Ok(reference.unchecked_into::<Element>().into())
+    } else if get(&reference, "getBoundingClientRect").is_some() {
+        let virtual_element: Box<dyn VirtualElement<Element>> =
+            Box::new(JsVirtualElement { object: reference });
+        Ok(virtual_element.into())
+    } else {
+        Err(JsError::new(
+            "Reference should be an element or a virtual element.",
+        ))
+    }
+}
@@ -0,0 +1,95 @@
+use floating_ui_dom::{
+    Arrow as CoreArrow, ArrowOptions as CoreArrowOptions, Middleware, MiddlewareReturn,
+    MiddlewareState, Padding, ARROW_NAME,
+};
+use yew::NodeRef;
+
+/// Options for [`Arrow`].
+#[derive(Clone)]
+pub struct ArrowOptions {
+    /// The arrow element to be positioned.
+    pub element: NodeRef,
+
+    /// The padding between the arrow element and the floating element edges.
+    /// Useful when the floating element has rounded corners.
+    ///
+    /// Defaults to `0` on all sides.
+    pub padding: Option<Padding>,
+
+    /// The offset of the arrow tip from the start of the arrow element along the alignment axis.
+    /// Useful for asymmetric arrow shapes, where the tip is not centered.
+    ///
+    /// Defaults to half the length of the arrow element.
+    pub tip_offset: Option<f64>,
+}
+
+impl ArrowOptions {
+    pub fn new(element: NodeRef) -> Self {
+        ArrowOptions {
+            element,
+            padding: None,
+            tip_offset: None,
+        }
+    }
+
+    /// Set `element` option.
+    pub fn element(mut self, value: NodeRef) -> Self {
+        self.element = value;
+        self
+    }
+
+    /// Set `padding` option.
+    pub fn padding(mut self, value: Padding) -> Self {
+        self.padding = Some(value);
+        self
+    }
+
+    /// Set `tip_offset` option.
+    pub fn tip_offset(mut self, value: f64) -> Self {
+        self.tip_offset = Some(value);
+        self
+    }
+}
+
+/// Provides data to position an inner element of the floating element so that it appears centered to the reference element.
+///
+/// See <https://floating-ui.com/docs/arrow> for the original documentation.
+#[derive(Clone)]
+pub struct Arrow {
+    options: ArrowOptions,
+}
+
+impl Arrow {
+    pub fn new(options: ArrowOptions) -> Self {
+        Arrow { options }
+    }
+}
+
+impl Middleware<web_sys::Element, web_sys::Window> for Arrow {
+    fn name(&self) -> &'static str {
+        ARROW_NAME
+    }
+
+    fn compute(
+        &self,
+        state: MiddlewareState<web_sys::Element, web_sys::Window>,
+    ) -> MiddlewareReturn {
+        let element = self.options.element.cast::<web_sys::Element>();
+
+        if let Some(element) = element {
+            CoreArrow::new(CoreArrowOptions {
+                element,
+                padding: self.options.padding.clone(),
+                tip_offset: self.options.tip_offset,
+            })
+            .compute(state)
+        } else {
+            MiddlewareReturn {
+                x: None,
+                y: None,
+                data: None,
+                reset: None,
+            }
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use std::rc::Rc;
+
+use web_sys::Element;
+use yew::{hook, use_effect, use_effect_with, use_mut_ref, use_state_eq, NodeRef};
+
+use floating_ui_dom::{
+    compute_position, ComputePositionConfig, MiddlewareData, OwnedElementOrVirtual, Placement,
+    Strategy, VirtualElement,
+};
+
+use crate::{
+    types::{FloatingStyles, UseFloatingOptions, UseFloatingReturn, WhileElementsMountedCleanupFn},
+    utils::{get_dpr::get_dpr, round_by_dpr::round_by_dpr},
+};
+
+#[derive(Clone)]
+pub enum VirtualElementOrNodeRef {
+    VirtualElement(Box<dyn VirtualElement<Element>>),
+    NodeRef(NodeRef),
+}
+
+impl VirtualElementOrNodeRef {
+    pub fn get(&self) -> Option<OwnedElementOrVirtual> {
+        match self {
+            VirtualElementOrNodeRef::VirtualElement(virtual_element) => {
+                Some(virtual_element.clone().into())
+            }
+            VirtualElementOrNodeRef::NodeRef(node_ref) => {
+                node_ref.cast::<Element>().map(|element| element.into())
+            }
+        }
+    }
+}
+
+impl From<Box<dyn VirtualElement<Element>>> for VirtualElementOrNodeRef {
+    fn from(value: Box<dyn VirtualElement<Element>>) -> Self {
+        VirtualElementOrNodeRef::VirtualElement(value)
+    }
+}
+
+impl From<NodeRef> for VirtualElementOrNodeRef {
+    fn from(value: NodeRef) -> Self {
+        VirtualElementOrNodeRef::NodeRef(value)
+    }
+}
+
+/// Elements and callback which `while_elements_mounted` was last called with.
+#[derive(PartialEq)]
+struct Attached {
+    reference: Option<Element>,
+    floating: Option<Element>,
+    while_elements_mounted: Option<*const ()>,
+}
+
+/// Computes the `x` and `y` coordinates that will place the floating element next to a reference element.
+#[hook]
+pub fn use_floating(
+    reference: VirtualElementOrNodeRef,
+    floating: NodeRef,
+    options: UseFloatingOptions,
+) -> UseFloatingReturn {
+    let open_option = options.open.unwrap_or(true);
+    let placement_option = options.placement.unwrap_or(Placement::Bottom);
+    let strategy_option = options.strategy.unwrap_or(Strategy::Absolute);
+    let transform_option = options.transform.unwrap_or(true);
+
+    // The states only re-render when they change, so `update` can run after every render.
+    let x = use_state_eq(|| 0.0);
+    let y = use_state_eq(|| 0.0);
+    let strategy = use_state_eq(|| strategy_option);
+    let placement = use_state_eq(|| placement_option);
+    let middleware_data = use_state_eq(MiddlewareData::default);
+    let is_positioned = use_state_eq(|| false);
+
+    // The latest arguments, read by `update` outside of rendering.
+    let latest_reference = use_mut_ref(|| reference.clone());
+    let latest_floating = use_mut_ref(|| floating.clone());
+    let latest_options = use_mut_ref(|| options.clone());
+    *latest_reference.borrow_mut() = reference;
+    *latest_floating.borrow_mut() = floating.clone();
+    *latest_options.borrow_mut() = options;
+
+    // The placement of the latest computation, passed as the previous placement to the next one.
+    let latest_placement = use_mut_ref(|| None::<Placement>);
+
+    let initial_styles = FloatingStyles {
+        position: *strategy,
+        top: "0".into(),
+        left: "0".into(),
+        transform: None,
+        will_change: None,
+    };
+    let floating_styles = match floating.cast::<Element>() {
+        Some(floating_element) => {
+            let x_val = round_by_dpr(&floating_element, *x);
+            let y_val = round_by_dpr(&floating_element, *y);
+
+            if transform_option {
+                FloatingStyles {
+                    transform: Some(format!("translate({x_val}px, {y_val}px)")),
+                    will_change: match get_dpr(&floating_element) >= 1.5 {
+                        true => Some("transform".into()),
+                        false => None,
+                    },
+                    ..initial_styles
+                }
+            } else {
+                FloatingStyles {
+                    left: format!("{x_val}px"),
+                    top: format!("{y_val}px"),
+                    ..initial_styles
+                }
+            }
+        }
+        None => initial_styles,
+    };
+
+    let update: Rc<dyn Fn()> = {
+        let latest_reference = latest_reference.clone();
+        let latest_floating = latest_floating.clone();
+        let latest_options = latest_options.clone();
+        let latest_placement = latest_placement.clone();
+        let set_x = x.setter();
+        let set_y = y.setter();
+        let set_strategy = strategy.setter();
+        let set_placement = placement.setter();
+        let set_middleware_data = middleware_data.setter();
+        let set_is_positioned = is_positioned.setter();
+
+        Rc::new(move || {
+            let reference_element = latest_reference.borrow().get();
+            let floating_element = latest_floating.borrow().cast::<Element>();
+
+            if let (Some(reference_element), Some(floating_element)) =
+                (reference_element, floating_element)
+            {
+                let options = latest_options.borrow();
+                let config = ComputePositionConfig {
+                    placement: Some(options.placement.unwrap_or(Placement::Bottom)),
+                    strategy: Some(options.strategy.unwrap_or(Strategy::Absolute)),
+                    middleware: options.middleware.clone(),
+                    previous_placement: *latest_placement.borrow(),
+                };
+
+                let position =
+                    compute_position((&reference_element).into(), &floating_element, Some(config));
+                set_x.set(position.x);
+                set_y.set(position.y);
+                set_strategy.set(position.strategy);
+                latest_placement.replace(Some(position.placement));
+                set_placement.set(position.placement);
+                set_middleware_data.set(position.middleware_data);
+                // The position is computed after every render, so a closed floating element must stay unpositioned.
+                set_is_positioned.set(options.open.unwrap_or(true));
+            }
+        })
+    };
+
+    let while_elements_mounted_cleanup = use_mut_ref(|| None::<WhileElementsMountedCleanupFn>);
+    let attached = use_mut_ref(|| None::<Attached>);
+
+    // Effects run after the node refs are attached, so the elements are compared on every render.
+    {
+        let update = update.clone();
+        let latest_reference = latest_reference.clone();
+        let latest_options = latest_options.clone();
+        let while_elements_mounted_cleanup = while_elements_mounted_cleanup.clone();
+
+        use_effect(move || {
+            let reference_element = latest_reference.borrow().get();
+            let floating_element = floating.cast::<Element>();
+            let while_elements_mounted = latest_options.borrow().while_elements_mounted.clone();
+
+            let next = Attached {
+                reference: reference_element
+                    .clone()
+                    .and_then(|reference| reference.resolve()),
+                floating: floating_element.clone(),
+                while_elements_mounted: while_elements_mounted
+                    .as_ref()
+                    .map(|while_elements_mounted| Rc::as_ptr(while_elements_mounted) as *const ()),
+            };
+
+            if attached.borrow().as_ref() != Some(&next) {
+                if let Some(cleanup) = while_elements_mounted_cleanup.borrow_mut().take() {
+                    cleanup();
+                }
+
+                match (while_elements_mounted, reference_element, floating_element) {
+                    (
+                        Some(while_elements_mounted),
+                        Some(reference_element),
+                        Some(floating_element),
+                    ) => {
+                        while_elements_mounted_cleanup.replace(Some(while_elements_mounted(
+                            (&reference_element).into(),
+                            &floating_element,
+                            update,
+                        )));
+                    }
+                    (None, _, _) => update(),
+                    _ => {}
+                }
+
+                attached.replace(Some(next));
+            }
+        });
+    }
+
+    // Middleware can not be compared, so the position is computed after every render to pick up changed options.
+    {
+        let update = update.clone();
+        use_effect(move || {
+            update();
+        });
+    }
+
+    {
+        let is_positioned = is_positioned.clone();
+        let latest_placement = latest_placement.clone();
+        use_effect_with(open_option, move |open| {
+            if !open {
+                latest_placement.replace(None);
+                is_positioned.set(false);
+            }
+        });
+    }
+
+    use_effect_with((), move |_| {
+        move || {
+            if let Some(cleanup) = while_elements_mounted_cleanup.borrow_mut().take() {
+                cleanup();
+            }
+        }
+    });
+
+    UseFloatingReturn {
+        x: *x,
+        y: *y,
+        placement: *placement,
+        strategy: *strategy,
+        middleware_data: (*middleware_data).clone(),
+        is_positioned: *is_positioned,
+        floating_styles,
+        update,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, time::Duration};
+
+    use wasm_bindgen_test::*;
+    use yew::{platform::time::sleep, prelude::*};
+
+    use floating_ui_dom::{Offset, OffsetOptions};
+
+    use super::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    fn render<C: BaseComponent<Properties = ()>>() -> Element {
+        let document = web_sys::window().unwrap().document().unwrap();
+        let root = document.create_element("div").unwrap();
+        document.body().unwrap().append_child(&root).unwrap();
+
+        yew::Renderer::<C>::with_root(root.clone()).render();
+        root
+    }
+
+    #[wasm_bindgen_test]
+    async fn updates_is_positioned_when_position_is_computed() {
+        #[function_component]
+        fn Component() -> Html {
+            let reference = use_node_ref();
+            let floating = use_node_ref();
+            let UseFloatingReturn { is_positioned, .. } = use_floating(
+                reference.clone().into(),
+                floating.clone(),
+                UseFloatingOptions::default(),
+            );
+
+            html! {
+                <>
+                    <div ref={reference} />
+                    <div ref={floating} />
+                    <div class="test-is-positioned">{is_positioned}</div>
+                </>
+            }
+        }
+
+        let root = render::<Component>();
+        sleep(Duration::ZERO).await;
+
+        assert_eq!(
+            root.query_selector(".test-is-positioned")
+                .unwrap()
+                .and_then(|element| element.text_content()),
+            Some("true".into())
+        );
+    }
+
+    #[wasm_bindgen_test]
+    async fn resets_is_positioned_when_closed() {
+        thread_local! {
+            static SET_OPEN: RefCell<Option<UseStateSetter<bool>>> = const { RefCell::new(None) };
+        }
+
+        #[function_component]
+        fn Component() -> Html {
+            let reference = use_node_ref();
+            let floating = use_node_ref();
+            let open = use_state(|| true);
+            let UseFloatingReturn { is_positioned, .. } = use_floating(
+                reference.clone().into(),
+                floating.clone(),
+                UseFloatingOptions::default().open(*open),
+            );
+
+            SET_OPEN.with(|set_open| set_open.replace(Some(open.setter())));
+
+            html! {
+                <>
+                    <div ref={reference} />
+                    <div ref={floating} />
+                    <div class="test-is-positioned-when-closed">{is_positioned}</div>
+                </>
+            }
+        }
+
+        let set_open = |open: bool| {
+            SET_OPEN.with(|set_open| {
+                set_open
+                    .borrow()
+                    .as_ref()
+                    .expect("Setter should be stored.")
+                    .set(open)
+            })
+        };
+
+        let root = render::<Component>();
+        let is_positioned = || {
+            root.query_selector(".test-is-positioned-when-closed")
+                .unwrap()
+                .and_then(|element| element.text_content())
+        };
+
+        sleep(Duration::ZERO).await;
+        assert_eq!(is_positioned(), Some("true".into()));
+
+        set_open(false);
+        sleep(Duration::ZERO).await;
+        assert_eq!(is_positioned(), Some("false".into()));
+
+        set_open(true);
+        sleep(Duration::ZERO).await;
+        assert_eq!(is_positioned(), Some("true".into()));
+    }
+
+    #[wasm_bindgen_test]
+    async fn updates_position_when_middleware_options_change() {
+        thread_local! {
+            static POSITIONS: RefCell<Vec<(f64, f64)>> = const { RefCell::new(vec![]) };
+        }
+
+        #[function_component]
+        fn Component() -> Html {
+            let reference = use_node_ref();
+            let floating = use_node_ref();
+            let offset = use_state(|| 0.0);
+            let UseFloatingReturn {
+                y, is_positioned, ..
+            } = use_floating(
+                reference.clone().into(),
+                floating.clone(),
+                UseFloatingOptions::default()
+                    .middleware(vec![Box::new(Offset::new(OffsetOptions::Value(*offset)))]),
+            );
+
+            if is_positioned {
+                POSITIONS.with(|positions| positions.borrow_mut().push((*offset, y)));
+            }
+
+            {
+                let offset = offset.clone();
+                use_effect_with(is_positioned, move |is_positioned| {
+                    if *is_positioned {
+                        offset.set(10.0);
+                    }
+                });
+            }
+
+            html! {
+                <>
+                    <div ref={reference} />
+                    <div ref={floating} />
+                </>
+            }
+        }
+
+        render::<Component>();
+        sleep(Duration::ZERO).await;
+
+        let positions = POSITIONS.with(|positions| positions.borrow().clone());
+        let (first_offset, first_y) = positions.first().copied().unwrap();
+        assert_eq!(first_offset, 0.0);
+        assert_eq!(positions.last().copied(), Some((10.0, first_y + 10.0)));
+    }
+}
@@ -74,6 +74,7 @@ pub fn App() -> impl IntoView {
             style:left=move || floating_styles().style_left()
             style:transform=move || floating_styles().style_transform()
             style:will-change=move || floating_styles().style_will_change()
+            style:visibility=move || floating_styles().style_visibility()
         >
             My tooltip with more content
             <div